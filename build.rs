@@ -1,6 +1,8 @@
 use std::env;
 
 pub fn main() {
+    println!("cargo:rustc-check-cfg=cfg(build, values(\"debug\", \"release\"))");
+    println!("cargo:rustc-check-cfg=cfg(profile, values(\"release\"))");
     if let Ok(profile) = env::var("PROFILE") {
         println!("cargo:rustc-cfg=build={:?}", profile);
     }
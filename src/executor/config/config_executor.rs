@@ -1,16 +1,133 @@
-use super::uvm_config::{ConfigError, UvmConfig};
+use super::super::language::Vendor;
+use super::uvm_config::{
+    find_project_config, get_user_config_path, ConfigError, GeneralLanguageConfig, JavaConfig,
+    UvmConfig,
+};
 use log::info;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 use thiserror::Error;
 
 pub struct ConfigContext {
     pub home_dir: PathBuf,
+    /// used to find project configuration file.
+    pub current_dir: PathBuf,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// ConfigKey identifies an option in the configuration file,
+/// options of a language table are written as dotted keys, e.g. `node.mirror`,
+/// aliases are written with their names, e.g. `go.aliases.work`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ConfigKey {
     Proxy,
+    DataDir,
+    InstallableOnly,
+    IndexTtl,
+    Offline,
+    KeepArchives,
+    AllowProjectNetwork,
+    GoProxy,
+    GoMirror,
+    NodeProxy,
+    NodeMirror,
+    JavaProxy,
+    JavaMirror,
+    JavaDefaultVendor,
+    Alias(String),
+    GoAlias(String),
+    NodeAlias(String),
+    JavaAlias(String),
+}
+
+/// tables of aliases, aliases of the top-level table apply to all languages.
+static ALIAS_TABLES: [&str; 4] = ["aliases", "go.aliases", "node.aliases", "java.aliases"];
+
+static ALL_KEYS: [ConfigKey; 14] = [
+    ConfigKey::Proxy,
+    ConfigKey::DataDir,
+    ConfigKey::InstallableOnly,
+    ConfigKey::IndexTtl,
+    ConfigKey::Offline,
+    ConfigKey::KeepArchives,
+    ConfigKey::AllowProjectNetwork,
+    ConfigKey::GoProxy,
+    ConfigKey::GoMirror,
+    ConfigKey::NodeProxy,
+    ConfigKey::NodeMirror,
+    ConfigKey::JavaProxy,
+    ConfigKey::JavaMirror,
+    ConfigKey::JavaDefaultVendor,
+];
+
+impl ConfigKey {
+    pub fn from_str(s: &str) -> Option<Self> {
+        if let Some(key) = ALL_KEYS.iter().find(|k| k.name() == s) {
+            return Some(key.clone());
+        }
+        let (table, name) = s.rsplit_once('.').filter(|(t, _)| ALIAS_TABLES.contains(t))?;
+        if !is_valid_alias_name(name) {
+            return None;
+        }
+        let name = name.to_string();
+        match table {
+            "aliases" => Some(Self::Alias(name)),
+            "go.aliases" => Some(Self::GoAlias(name)),
+            "node.aliases" => Some(Self::NodeAlias(name)),
+            _ => Some(Self::JavaAlias(name)),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        let name = match self {
+            Self::Proxy => "proxy",
+            Self::DataDir => "data_dir",
+            Self::InstallableOnly => "installable_only",
+            Self::IndexTtl => "index_ttl",
+            Self::Offline => "offline",
+            Self::KeepArchives => "keep_archives",
+            Self::AllowProjectNetwork => "allow_project_network",
+            Self::GoProxy => "go.proxy",
+            Self::GoMirror => "go.mirror",
+            Self::NodeProxy => "node.proxy",
+            Self::NodeMirror => "node.mirror",
+            Self::JavaProxy => "java.proxy",
+            Self::JavaMirror => "java.mirror",
+            Self::JavaDefaultVendor => "java.default_vendor",
+            Self::Alias(name) => return format!("aliases.{}", name),
+            Self::GoAlias(name) => return format!("go.aliases.{}", name),
+            Self::NodeAlias(name) => return format!("node.aliases.{}", name),
+            Self::JavaAlias(name) => return format!("java.aliases.{}", name),
+        };
+        name.to_string()
+    }
+
+    pub fn all() -> &'static [ConfigKey] {
+        &ALL_KEYS
+    }
+
+    /// names of all options, aliases are written as `<table>.<name>`.
+    pub fn all_names() -> Vec<String> {
+        let mut names: Vec<_> = ALL_KEYS.iter().map(|k| k.name()).collect();
+        names.extend(ALIAS_TABLES.iter().map(|t| format!("{}.<name>", t)));
+        names
+    }
+
+    /// whether `value` is valid for the option, boolean options accept `true` and `false`,
+    /// `index_ttl` accepts minutes, `java.default_vendor` accepts supported vendors,
+    /// aliases accept versions without whitespaces.
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            Self::InstallableOnly | Self::Offline | Self::KeepArchives | Self::AllowProjectNetwork => {
+                value.parse::<bool>().is_ok()
+            }
+            Self::IndexTtl => value.parse::<u64>().is_ok(),
+            Self::JavaDefaultVendor => Vendor::from_str(&value.to_lowercase()).is_some(),
+            Self::Alias(_) | Self::GoAlias(_) | Self::NodeAlias(_) | Self::JavaAlias(_) => {
+                !value.is_empty() && !value.contains(char::is_whitespace)
+            }
+            _ => true,
+        }
+    }
 }
 
 pub struct ConfigExecutor {}
@@ -23,54 +140,264 @@ impl ConfigExecutor {
         Ok(config)
     }
 
-    pub fn get(
+    /// load user configuration and project configuration, from low precedence to high.
+    fn load_layers(&self, context: &ConfigContext) -> Result<Vec<(PathBuf, UvmConfig)>> {
+        let user_path = get_user_config_path(&context.home_dir);
+        let user_config = UvmConfig::load_file(&user_path)?;
+        let allow_network = user_config.allow_project_network.unwrap_or(false);
+        let mut layers = vec![(user_path, user_config)];
+        if let Some(path) = find_project_config(&context.current_dir) {
+            let (config, _) = UvmConfig::load_file(&path)?.restrict_project(allow_network);
+            layers.push((path, config));
+        }
+        Ok(layers)
+    }
+
+    /// get effective values, and the configuration files they come from.
+    pub fn get_with_origin(
         &self,
         keys: HashSet<ConfigKey>,
         context: &ConfigContext,
-    ) -> Result<HashMap<ConfigKey, String>> {
-        let config = self.load(context)?;
+    ) -> Result<HashMap<ConfigKey, (String, PathBuf)>> {
+        let layers = self.load_layers(context)?;
         let mut result = HashMap::new();
         for key in keys {
-            match key {
-                ConfigKey::Proxy => {
-                    if let Some(proxy) = &config.proxy {
-                        result.insert(key, proxy.clone());
-                    }
+            for (path, config) in layers.iter().rev() {
+                if let Some(value) = get_value(config, &key) {
+                    result.insert(key, (value, path.clone()));
+                    break;
                 }
             }
         }
         Ok(result)
     }
 
+    pub fn get(
+        &self,
+        keys: HashSet<ConfigKey>,
+        context: &ConfigContext,
+    ) -> Result<HashMap<ConfigKey, String>> {
+        let result = self.get_with_origin(keys, context)?;
+        Ok(result.into_iter().map(|(k, (v, _))| (k, v)).collect())
+    }
+
+    /// get all options which have been set, including aliases of all configuration files.
+    pub fn list(&self, context: &ConfigContext) -> Result<HashMap<ConfigKey, (String, PathBuf)>> {
+        let mut keys: HashSet<ConfigKey> = ConfigKey::all().iter().cloned().collect();
+        for (_, config) in self.load_layers(context)? {
+            keys.extend(get_alias_keys(&config));
+        }
+        self.get_with_origin(keys, context)
+    }
+
     pub fn set(&self, kvs: HashMap<ConfigKey, String>, context: &ConfigContext) -> Result<()> {
+        if let Some((key, value)) = kvs.iter().find(|(k, v)| !k.is_valid(v)) {
+            return Err(ConfigExecutorError::InvalidValue(key.name(), value.clone()));
+        }
         let mut config = self.load(context)?;
         for (key, value) in kvs {
-            match key {
-                ConfigKey::Proxy => {
-                    config.proxy = Some(value);
-                }
-            }
+            set_value(&mut config, key, Some(value));
         }
         info!("save config: {:?}", &config);
+        config.save(&context.home_dir)?;
         Ok(())
     }
 
     pub fn del(&self, keys: HashSet<ConfigKey>, context: &ConfigContext) -> Result<()> {
         let mut config = self.load(context)?;
         for key in keys {
-            match key {
-                ConfigKey::Proxy => {
-                    config.proxy = None;
-                }
-            }
+            set_value(&mut config, key, None);
         }
+        info!("save config: {:?}", &config);
         config.save(&context.home_dir)?;
         Ok(())
     }
 }
 
+/// the alias named `name` in `aliases`.
+fn get_alias(aliases: Option<&BTreeMap<String, String>>, name: &str) -> Option<String> {
+    aliases.and_then(|a| a.get(name).cloned())
+}
+
+/// keys of all aliases in `config`.
+fn get_alias_keys(config: &UvmConfig) -> Vec<ConfigKey> {
+    let mut keys = vec![];
+    let mut add = |aliases: Option<&BTreeMap<String, String>>, key: fn(String) -> ConfigKey| {
+        keys.extend(aliases.into_iter().flat_map(|a| a.keys().cloned().map(key)));
+    };
+    add(config.aliases.as_ref(), ConfigKey::Alias);
+    add(config.go.as_ref().and_then(|c| c.aliases.as_ref()), ConfigKey::GoAlias);
+    add(config.node.as_ref().and_then(|c| c.aliases.as_ref()), ConfigKey::NodeAlias);
+    add(config.java.as_ref().and_then(|c| c.aliases.as_ref()), ConfigKey::JavaAlias);
+    keys
+}
+
+fn get_value(config: &UvmConfig, key: &ConfigKey) -> Option<String> {
+    match key {
+        ConfigKey::Proxy => config.proxy.clone(),
+        ConfigKey::DataDir => config.data_dir.clone(),
+        ConfigKey::InstallableOnly => config.installable_only.map(|v| v.to_string()),
+        ConfigKey::IndexTtl => config.index_ttl.map(|v| v.to_string()),
+        ConfigKey::Offline => config.offline.map(|v| v.to_string()),
+        ConfigKey::KeepArchives => config.keep_archives.map(|v| v.to_string()),
+        ConfigKey::AllowProjectNetwork => config.allow_project_network.map(|v| v.to_string()),
+        ConfigKey::GoProxy => config.go.as_ref().and_then(|c| c.proxy.clone()),
+        ConfigKey::GoMirror => config.go.as_ref().and_then(|c| c.mirror.clone()),
+        ConfigKey::NodeProxy => config.node.as_ref().and_then(|c| c.proxy.clone()),
+        ConfigKey::NodeMirror => config.node.as_ref().and_then(|c| c.mirror.clone()),
+        ConfigKey::JavaProxy => config.java.as_ref().and_then(|c| c.proxy.clone()),
+        ConfigKey::JavaMirror => config.java.as_ref().and_then(|c| c.mirror.clone()),
+        ConfigKey::JavaDefaultVendor => config.java.as_ref().and_then(|c| c.default_vendor.clone()),
+        ConfigKey::Alias(name) => get_alias(config.aliases.as_ref(), name),
+        ConfigKey::GoAlias(name) => get_alias(config.go.as_ref().and_then(|c| c.aliases.as_ref()), name),
+        ConfigKey::NodeAlias(name) => get_alias(config.node.as_ref().and_then(|c| c.aliases.as_ref()), name),
+        ConfigKey::JavaAlias(name) => get_alias(config.java.as_ref().and_then(|c| c.aliases.as_ref()), name),
+    }
+}
+
+/// set or delete(when `value` is `None`) an option,
+/// a language table is removed once all of its options are deleted.
+fn set_value(config: &mut UvmConfig, key: ConfigKey, value: Option<String>) {
+    match key {
+        ConfigKey::Proxy => config.proxy = value,
+        ConfigKey::DataDir => config.data_dir = value,
+        ConfigKey::InstallableOnly => config.installable_only = value.and_then(|v| v.parse().ok()),
+        ConfigKey::IndexTtl => config.index_ttl = value.and_then(|v| v.parse().ok()),
+        ConfigKey::Offline => config.offline = value.and_then(|v| v.parse().ok()),
+        ConfigKey::KeepArchives => config.keep_archives = value.and_then(|v| v.parse().ok()),
+        ConfigKey::AllowProjectNetwork => {
+            config.allow_project_network = value.and_then(|v| v.parse().ok())
+        }
+        ConfigKey::GoProxy => update_general(&mut config.go, |c| c.proxy = value),
+        ConfigKey::GoMirror => update_general(&mut config.go, |c| c.mirror = value),
+        ConfigKey::NodeProxy => update_general(&mut config.node, |c| c.proxy = value),
+        ConfigKey::NodeMirror => update_general(&mut config.node, |c| c.mirror = value),
+        ConfigKey::JavaProxy => update_java(&mut config.java, |c| c.proxy = value),
+        ConfigKey::JavaMirror => update_java(&mut config.java, |c| c.mirror = value),
+        ConfigKey::JavaDefaultVendor => update_java(&mut config.java, |c| c.default_vendor = value),
+        ConfigKey::Alias(name) => set_alias(&mut config.aliases, name, value),
+        ConfigKey::GoAlias(name) => update_general(&mut config.go, |c| set_alias(&mut c.aliases, name, value)),
+        ConfigKey::NodeAlias(name) => update_general(&mut config.node, |c| set_alias(&mut c.aliases, name, value)),
+        ConfigKey::JavaAlias(name) => update_java(&mut config.java, |c| set_alias(&mut c.aliases, name, value)),
+    }
+}
+
+/// set or delete an alias, the table is removed once all of its aliases are deleted.
+fn set_alias(aliases: &mut Option<BTreeMap<String, String>>, name: String, value: Option<String>) {
+    let mut a = aliases.take().unwrap_or_default();
+    match value {
+        Some(v) => a.insert(name, v),
+        None => a.remove(&name),
+    };
+    if !a.is_empty() {
+        *aliases = Some(a);
+    }
+}
+
+/// alias names start with a letter, so they never shadow versions like `1.21`.
+fn is_valid_alias_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic()) && !name.contains(char::is_whitespace)
+}
+
+fn update_general<F: FnOnce(&mut GeneralLanguageConfig)>(
+    config: &mut Option<GeneralLanguageConfig>,
+    f: F,
+) {
+    let mut c = config.take().unwrap_or_default();
+    f(&mut c);
+    if c.proxy.is_some() || c.mirror.is_some() || c.aliases.is_some() {
+        *config = Some(c);
+    }
+}
+
+fn update_java<F: FnOnce(&mut JavaConfig)>(config: &mut Option<JavaConfig>, f: F) {
+    let mut c = config.take().unwrap_or_default();
+    f(&mut c);
+    if c.proxy.is_some() || c.mirror.is_some() || c.default_vendor.is_some() || c.aliases.is_some() {
+        *config = Some(c);
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ConfigExecutorError {
     #[error("{0}")]
     ConfigError(#[from] ConfigError),
+    #[error("invalid value `{1}` for option `{0}`")]
+    InvalidValue(String, String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_alias_keys, get_value, set_value, ConfigKey};
+    use crate::executor::config::uvm_config::UvmConfig;
+
+    #[test]
+    fn test_config_key() {
+        for key in ConfigKey::all() {
+            assert_eq!(Some(key.clone()), ConfigKey::from_str(&key.name()));
+        }
+        assert_eq!(Some(ConfigKey::NodeMirror), ConfigKey::from_str("node.mirror"));
+        assert_eq!(None, ConfigKey::from_str("go.default_vendor"));
+        assert!(ConfigKey::InstallableOnly.is_valid("true"));
+        assert!(!ConfigKey::InstallableOnly.is_valid("yes"));
+        assert!(ConfigKey::JavaDefaultVendor.is_valid("Corretto"));
+        assert!(!ConfigKey::JavaDefaultVendor.is_valid("oracle"));
+
+        assert_eq!(Some(ConfigKey::GoAlias("work".to_string())), ConfigKey::from_str("go.aliases.work"));
+        assert_eq!(Some(ConfigKey::Alias("work-go".to_string())), ConfigKey::from_str("aliases.work-go"));
+        assert_eq!("node.aliases.legacy", ConfigKey::NodeAlias("legacy".to_string()).name());
+        assert_eq!(None, ConfigKey::from_str("go.aliases.1.21"));
+        assert_eq!(None, ConfigKey::from_str("go.aliases."));
+        assert_eq!(None, ConfigKey::from_str("rust.aliases.work"));
+        assert!(ConfigKey::JavaAlias("legacy".to_string()).is_valid("corretto-8"));
+        assert!(!ConfigKey::JavaAlias("legacy".to_string()).is_valid(""));
+        assert!(!ConfigKey::JavaAlias("legacy".to_string()).is_valid("corretto 8"));
+    }
+
+    #[test]
+    fn test_set_value() {
+        let mut config: UvmConfig = toml::from_str("").unwrap();
+        set_value(&mut config, ConfigKey::NodeMirror, Some("https://npmmirror.com/mirrors/node/".to_string()));
+        set_value(&mut config, ConfigKey::JavaDefaultVendor, Some("corretto".to_string()));
+        assert_eq!(
+            Some("https://npmmirror.com/mirrors/node/".to_string()),
+            get_value(&config, &ConfigKey::NodeMirror)
+        );
+        assert_eq!(None, get_value(&config, &ConfigKey::NodeProxy));
+
+        let content = toml::to_string(&config).unwrap();
+        let loaded: UvmConfig = toml::from_str(&content).unwrap();
+        assert_eq!(Some("corretto".to_string()), get_value(&loaded, &ConfigKey::JavaDefaultVendor));
+
+        set_value(&mut config, ConfigKey::NodeMirror, None);
+        assert!(config.node.is_none());
+        assert!(config.java.is_some());
+    }
+
+    #[test]
+    fn test_set_alias() {
+        let mut config: UvmConfig = toml::from_str("[go.aliases]\nwork = \"1.21\"").unwrap();
+        set_value(&mut config, ConfigKey::Alias("work-go".to_string()), Some("1.21".to_string()));
+        set_value(&mut config, ConfigKey::NodeAlias("legacy".to_string()), Some("lts/gallium".to_string()));
+        let content = toml::to_string(&config).unwrap();
+        let loaded: UvmConfig = toml::from_str(&content).unwrap();
+        assert_eq!(Some("1.21".to_string()), get_value(&loaded, &ConfigKey::Alias("work-go".to_string())));
+        assert_eq!(Some("1.21".to_string()), get_value(&loaded, &ConfigKey::GoAlias("work".to_string())));
+        let mut keys = get_alias_keys(&loaded);
+        keys.sort();
+        assert_eq!(
+            vec![
+                ConfigKey::Alias("work-go".to_string()),
+                ConfigKey::GoAlias("work".to_string()),
+                ConfigKey::NodeAlias("legacy".to_string()),
+            ],
+            keys
+        );
+
+        set_value(&mut config, ConfigKey::GoAlias("work".to_string()), None);
+        set_value(&mut config, ConfigKey::NodeAlias("legacy".to_string()), None);
+        assert!(config.go.is_none());
+        assert!(config.node.is_none());
+        assert!(config.aliases.is_some());
+    }
 }
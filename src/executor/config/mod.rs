@@ -1,5 +1,5 @@
 mod uvm_config;
 mod config_executor;
 
-pub use uvm_config::load_config;
+pub use uvm_config::{load_config, merge_aliases, GeneralLanguageConfig, UvmConfig};
 pub use config_executor::{ConfigExecutor, ConfigKey, ConfigContext};
\ No newline at end of file
@@ -1,45 +1,75 @@
 use log::error;
 use serde::{self, Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::{Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct UvmConfig {
     pub proxy: Option<String>,
     pub data_dir: Option<String>,
+    /// list installable versions for current platform only by default.
+    pub installable_only: Option<bool>,
+    /// minutes a cached remote version index is used without revalidation.
+    pub index_ttl: Option<u64>,
+    /// use cached version indexes and downloaded archives only.
+    pub offline: Option<bool>,
+    /// keep downloaded archives after installing.
+    pub keep_archives: Option<bool>,
+    /// allow project configuration files to set proxies and mirrors.
+    pub allow_project_network: Option<bool>,
+    /// user-defined version aliases of all languages, e.g. `work-go = "1.21"` in `[aliases]`,
+    /// aliases of a language table replace those with the same names.
+    pub aliases: Option<BTreeMap<String, String>>,
     pub go: Option<GeneralLanguageConfig>,
     pub node: Option<GeneralLanguageConfig>,
     pub java: Option<JavaConfig>,
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct GeneralLanguageConfig {
     pub proxy: Option<String>,
     pub mirror: Option<String>,
+    /// user-defined version aliases, e.g. `work = "1.21"` in `[go.aliases]`.
+    pub aliases: Option<BTreeMap<String, String>>,
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct JavaConfig {
     pub proxy: Option<String>,
     pub mirror: Option<String>,
     pub default_vendor: Option<String>,
+    /// user-defined version aliases, e.g. `legacy = "corretto-8"` in `[java.aliases]`.
+    pub aliases: Option<BTreeMap<String, String>>,
 }
 
 static DEFAULT_CONFIG: UvmConfig = UvmConfig {
     proxy: None,
     data_dir: None,
+    installable_only: None,
+    index_ttl: None,
+    offline: None,
+    keep_archives: None,
+    allow_project_network: None,
+    aliases: None,
     go: None,
     node: None,
     java: None,
 };
 
+/// name of project configuration file, which is searched from current dir up to root.
+pub static PROJECT_CONFIG_FILE: &str = ".uvm.toml";
+
 type Result<T> = std::result::Result<T, ConfigError>;
 
 impl UvmConfig {
     pub fn load_config(home_dir: &Path) -> Result<UvmConfig> {
-        let config_path = home_dir.join("config");
+        UvmConfig::load_file(&get_user_config_path(home_dir))
+    }
+
+    pub fn load_file(config_path: &Path) -> Result<UvmConfig> {
         let mut config_file;
         if !config_path.exists() || !config_path.is_file() {
             Ok(DEFAULT_CONFIG.clone())
@@ -52,8 +82,61 @@ impl UvmConfig {
         }
     }
 
+    /// values of `other` override values of `self` field by field.
+    pub fn merge(self, other: UvmConfig) -> UvmConfig {
+        UvmConfig {
+            proxy: other.proxy.or(self.proxy),
+            data_dir: other.data_dir.or(self.data_dir),
+            installable_only: other.installable_only.or(self.installable_only),
+            index_ttl: other.index_ttl.or(self.index_ttl),
+            offline: other.offline.or(self.offline),
+            keep_archives: other.keep_archives.or(self.keep_archives),
+            allow_project_network: other.allow_project_network.or(self.allow_project_network),
+            aliases: merge_aliases(self.aliases, other.aliases),
+            go: merge_general(self.go, other.go),
+            node: merge_general(self.node, other.node),
+            java: match (self.java, other.java) {
+                (Some(base), Some(over)) => Some(JavaConfig {
+                    proxy: over.proxy.or(base.proxy),
+                    mirror: over.mirror.or(base.mirror),
+                    default_vendor: over.default_vendor.or(base.default_vendor),
+                    aliases: merge_aliases(base.aliases, over.aliases),
+                }),
+                (base, over) => over.or(base),
+            },
+        }
+    }
+
+    /// remove options a project configuration file may not set, and return their names.
+    /// `data_dir` is user-level only, proxies and mirrors are removed unless `allow_network` is true.
+    pub fn restrict_project(mut self, allow_network: bool) -> (UvmConfig, Vec<&'static str>) {
+        let mut removed = vec![];
+        if self.data_dir.take().is_some() {
+            removed.push("data_dir");
+        }
+        if self.allow_project_network.take().is_some() {
+            removed.push("allow_project_network");
+        }
+        if !allow_network {
+            take_option(&mut self.proxy, "proxy", &mut removed);
+            if let Some(go) = self.go.as_mut() {
+                take_option(&mut go.proxy, "go.proxy", &mut removed);
+                take_option(&mut go.mirror, "go.mirror", &mut removed);
+            }
+            if let Some(node) = self.node.as_mut() {
+                take_option(&mut node.proxy, "node.proxy", &mut removed);
+                take_option(&mut node.mirror, "node.mirror", &mut removed);
+            }
+            if let Some(java) = self.java.as_mut() {
+                take_option(&mut java.proxy, "java.proxy", &mut removed);
+                take_option(&mut java.mirror, "java.mirror", &mut removed);
+            }
+        }
+        (self, removed)
+    }
+
     pub fn save(&self, home_dir: &Path) -> Result<()> {
-        let config_path = home_dir.join("config");
+        let config_path = get_user_config_path(home_dir);
         let mut config_file = OpenOptions::new()
             .write(true)
             .create(true)
@@ -65,14 +148,91 @@ impl UvmConfig {
     }
 }
 
-pub fn load_config(home_dir: &Path) -> UvmConfig {
-    match UvmConfig::load_config(home_dir) {
+fn merge_general(
+    base: Option<GeneralLanguageConfig>,
+    over: Option<GeneralLanguageConfig>,
+) -> Option<GeneralLanguageConfig> {
+    match (base, over) {
+        (Some(base), Some(over)) => Some(GeneralLanguageConfig {
+            proxy: over.proxy.or(base.proxy),
+            mirror: over.mirror.or(base.mirror),
+            aliases: merge_aliases(base.aliases, over.aliases),
+        }),
+        (base, over) => over.or(base),
+    }
+}
+
+/// aliases of `over` are added to `base`, and replace those with the same names.
+pub fn merge_aliases(
+    base: Option<BTreeMap<String, String>>,
+    over: Option<BTreeMap<String, String>>,
+) -> Option<BTreeMap<String, String>> {
+    match (base, over) {
+        (Some(mut base), Some(over)) => {
+            base.extend(over);
+            Some(base)
+        }
+        (base, over) => over.or(base),
+    }
+}
+
+fn take_option(option: &mut Option<String>, name: &'static str, removed: &mut Vec<&'static str>) {
+    if option.take().is_some() {
+        removed.push(name);
+    }
+}
+
+pub fn get_user_config_path(home_dir: &Path) -> PathBuf {
+    home_dir.join("config")
+}
+
+/// find the nearest project configuration file from `current_dir`.
+pub fn find_project_config(current_dir: &Path) -> Option<PathBuf> {
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// load user configuration, and layer project configuration over it,
+/// return warnings about project options which are ignored.
+pub fn load_config(home_dir: &Path, current_dir: &Path) -> (UvmConfig, Vec<String>) {
+    let user_config = match UvmConfig::load_config(home_dir) {
         Ok(config) => config,
         Err(err) => {
             error!("failed to load configuration: {}", err);
             DEFAULT_CONFIG.clone()
         }
-    }
+    };
+    let path = match find_project_config(current_dir) {
+        Some(path) => path,
+        None => return (user_config, vec![]),
+    };
+    let project_config = match UvmConfig::load_file(&path) {
+        Ok(config) => config,
+        Err(err) => {
+            error!("failed to load configuration {:?}: {}", path, err);
+            return (user_config, vec![]);
+        }
+    };
+    let allow_network = user_config.allow_project_network.unwrap_or(false);
+    let (project_config, removed) = project_config.restrict_project(allow_network);
+    let warnings = removed
+        .into_iter()
+        .map(|name| match name {
+            "data_dir" | "allow_project_network" => format!(
+                "`{}` in {} is ignored, it can only be set in user configuration",
+                name,
+                path.to_string_lossy()
+            ),
+            _ => format!(
+                "`{}` in {} is ignored, run `uvm config set allow_project_network true` to allow projects to set proxies and mirrors",
+                name,
+                path.to_string_lossy()
+            ),
+        })
+        .collect();
+    (user_config.merge(project_config), warnings)
 }
 
 #[derive(Error, Debug)]
@@ -84,3 +244,93 @@ pub enum ConfigError {
     #[error("config file error: {0}")]
     ConfigConvert(#[from] toml::ser::Error),
 }
+
+#[cfg(test)]
+mod test {
+    use super::{find_project_config, UvmConfig, PROJECT_CONFIG_FILE};
+    use crate::tool::test_dir::TestDir;
+    use std::fs;
+
+    #[test]
+    fn test_merge() {
+        let user: UvmConfig = toml::from_str(
+            r#"
+            proxy = "http://user:8080"
+            [node]
+            proxy = "http://node:8080"
+            mirror = "https://user.mirror/"
+            [node.aliases]
+            legacy = "lts/gallium"
+            [java]
+            default_vendor = "openjdk"
+            [go.aliases]
+            work = "1.20"
+            [aliases]
+            work-go = "1.20"
+            "#,
+        )
+        .unwrap();
+        let project: UvmConfig = toml::from_str(
+            r#"
+            [node]
+            mirror = "https://project.mirror/"
+            [java]
+            default_vendor = "corretto"
+            [go]
+            mirror = "https://golang.google.cn/dl/"
+            [go.aliases]
+            work = "1.21"
+            [aliases]
+            work-java = "21"
+            "#,
+        )
+        .unwrap();
+        let config = user.merge(project);
+        assert_eq!(Some("http://user:8080".to_string()), config.proxy);
+        let node = config.node.unwrap();
+        assert_eq!(Some("http://node:8080".to_string()), node.proxy);
+        assert_eq!(Some("https://project.mirror/".to_string()), node.mirror);
+        assert_eq!(Some("corretto".to_string()), config.java.unwrap().default_vendor);
+        let go = config.go.unwrap();
+        assert_eq!(Some("https://golang.google.cn/dl/".to_string()), go.mirror);
+        assert_eq!(Some(&"1.21".to_string()), go.aliases.unwrap().get("work"));
+        assert_eq!(Some(&"lts/gallium".to_string()), node.aliases.unwrap().get("legacy"));
+        let aliases = config.aliases.unwrap();
+        assert_eq!(Some(&"1.20".to_string()), aliases.get("work-go"));
+        assert_eq!(Some(&"21".to_string()), aliases.get("work-java"));
+    }
+
+    #[test]
+    fn test_restrict_project() {
+        let project: UvmConfig = toml::from_str(
+            r#"
+            data_dir = "/tmp/uvm"
+            proxy = "http://project:8080"
+            [node]
+            mirror = "https://project.mirror/"
+            [java]
+            default_vendor = "corretto"
+            "#,
+        )
+        .unwrap();
+        let (config, removed) = project.clone().restrict_project(false);
+        assert_eq!(vec!["data_dir", "proxy", "node.mirror"], removed);
+        assert!(config.data_dir.is_none() && config.proxy.is_none());
+        assert!(config.node.unwrap().mirror.is_none());
+        assert_eq!(Some("corretto".to_string()), config.java.unwrap().default_vendor);
+
+        let (config, removed) = project.restrict_project(true);
+        assert_eq!(vec!["data_dir"], removed);
+        assert_eq!(Some("http://project:8080".to_string()), config.proxy);
+    }
+
+    #[test]
+    fn test_find_project_config() {
+        let test_dir = TestDir::new("find_project_config");
+        let root = test_dir.path();
+        let nested = root.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(PROJECT_CONFIG_FILE), "").unwrap();
+        assert_eq!(Some(root.join(PROJECT_CONFIG_FILE)), find_project_config(&nested));
+    }
+}
@@ -0,0 +1,88 @@
+use super::super::language_executor::{format_semver, ProjectVersion};
+use super::version::parse_semver;
+use semver::VersionReq;
+use std::fs::read_to_string;
+use std::io::Result;
+use std::path::{Path, PathBuf};
+
+pub static GO_MOD_FILE: &str = "go.mod";
+
+/// GoMod contains version directives of a `go.mod` file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GoMod {
+    /// minimum Go version, e.g. `1.21` of `go 1.21`.
+    pub go: Option<String>,
+    /// preferred toolchain, e.g. `1.21.6` of `toolchain go1.21.6`.
+    pub toolchain: Option<String>,
+}
+
+impl GoMod {
+    pub fn parse(content: &str) -> Self {
+        let mut result = GoMod::default();
+        for line in content.lines() {
+            let line = line.split("//").next().unwrap_or("").trim();
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some("go"), Some(v)) => result.go = Some(v.to_string()),
+                (Some("toolchain"), Some(v)) if v != "default" => {
+                    result.toolchain = Some(v.strip_prefix("go").unwrap_or(v).to_string())
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// `toolchain` requires the exact version,
+    /// `go` is a minimum version, and the latest patch release of its minor version is installed by default.
+    pub fn to_project_version(&self, path: PathBuf) -> Option<ProjectVersion> {
+        if let Some(toolchain) = &self.toolchain {
+            let v = parse_semver(toolchain)?;
+            let req = VersionReq::parse(&format!("={}", format_semver(&v))).ok()?;
+            return Some(ProjectVersion { path, version: toolchain.clone(), reqs: vec![req], hint: None });
+        }
+        let v = parse_semver(self.go.as_ref()?)?;
+        let version = format!("~{}", format_semver(&v));
+        let req = VersionReq::parse(&format!(">={}", format_semver(&v))).ok()?;
+        Some(ProjectVersion { path, version, reqs: vec![req], hint: None })
+    }
+}
+
+/// find the nearest `go.mod` from `current_dir`.
+pub fn find_go_mod(current_dir: &Path) -> Result<Option<(PathBuf, GoMod)>> {
+    for dir in current_dir.ancestors() {
+        let path = dir.join(GO_MOD_FILE);
+        if path.is_file() {
+            let go_mod = GoMod::parse(&read_to_string(&path)?);
+            return Ok(Some((path, go_mod)));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::GoMod;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_go_mod() {
+        let content = "module example.com/a\n\ngo 1.21 // comment\n\ntoolchain go1.21.6\n\nrequire (\n\tgo.uber.org/zap v1.26.0\n)\n";
+        let go_mod = GoMod::parse(content);
+        assert_eq!(Some("1.21".to_string()), go_mod.go);
+        assert_eq!(Some("1.21.6".to_string()), go_mod.toolchain);
+        let version = go_mod.to_project_version(PathBuf::from("go.mod")).unwrap();
+        assert_eq!("1.21.6", version.version);
+        assert!(version.matches(&semver::Version::new(1, 21, 6)));
+        assert!(!version.matches(&semver::Version::new(1, 21, 7)));
+
+        let go_mod = GoMod::parse("module a\ngo 1.20\ntoolchain default\n");
+        let version = go_mod.to_project_version(PathBuf::from("go.mod")).unwrap();
+        assert_eq!("~1.20.0", version.version);
+        assert_eq!(">=1.20.0", version.requirement());
+        assert!(version.matches(&semver::Version::new(1, 20, 14)));
+        assert!(version.matches(&semver::Version::new(1, 21, 0)));
+        assert!(!version.matches(&semver::Version::new(1, 19, 13)));
+        assert_eq!(None, GoMod::parse("module a\n").to_project_version(PathBuf::from("go.mod")));
+    }
+}
@@ -1,15 +1,17 @@
 use super::super::language_executor::{
     format_semver, ExecutorContext, GeneralLanguageContext, LanguageError, LanguageExecutor,
-    LanguageVersion, Result,
+    LanguageEnv, LanguageVersion, PackageInfo, ProjectVersion, ReleaseInfo, Result,
 };
+use super::go_mod::find_go_mod;
 use super::html;
-use super::script::generate_scripts;
-use super::version::GolangVersion;
+use super::super::resolver::VersionResolver;
+use super::version::{parse_semver, GolangVersion};
 use crate::executor::language::language_executor::InstallResult;
 use crate::tool::checksum;
 use crate::tool::logger::{debug, error};
+use crate::tool::mirror::Mirror;
 use crate::tool::{fs, http, SupportedLanguage, GO};
-use semver::{Version, VersionReq};
+use semver::Version;
 use std::collections::HashSet;
 use std::fs::{remove_dir_all, remove_file, rename};
 use std::path::Path;
@@ -17,6 +19,9 @@ use tokio::runtime;
 
 static OFFICIAL_URL: &str = "https://go.dev/dl/";
 
+/// aliases of the latest stable release.
+static LATEST_ALIASES: [&str; 2] = ["latest", "stable"];
+
 type GoContext<'a> = ExecutorContext<'a, GeneralLanguageContext>;
 pub struct GolangExecutor {
     language: &'static SupportedLanguage,
@@ -31,6 +36,33 @@ impl GolangExecutor {
             .unwrap();
         GolangExecutor { language: GO, rt }
     }
+
+    /// get all releases from the download page of the official site or the mirror.
+    fn get_versions(&self, context: &GoContext) -> Result<Vec<GolangVersion>> {
+        let mirror = Mirror::new(OFFICIAL_URL, context.mirror.as_ref());
+        let version_maps = self
+            .rt
+            .block_on(html::parse_golang_official(&context.index_cache, &mirror.urls(""), &context.proxy))?;
+        let versions = version_maps
+            .into_iter()
+            .filter_map(|(k, v)| GolangVersion::from_map(k, v))
+            .collect();
+        Ok(versions)
+    }
+
+    /// find the release matched by `version` given by user, `latest` and `stable` match the latest stable release.
+    fn find_release<'v>(&self, version: &str, versions: &'v [GolangVersion], context: &GoContext) -> Result<Option<&'v GolangVersion>> {
+        let spec = if LATEST_ALIASES.contains(&version.to_lowercase().as_str()) { "*" } else { version };
+        let prerelease = context.language_context.as_ref().is_some_and(|c| c.prerelease);
+        let resolver = match VersionResolver::parse(spec, parse_semver) {
+            Some(r) => r.with_prerelease(prerelease),
+            None => {
+                return Err(LanguageError::GeneralString(format!("cannot parse the given version({})", version)));
+            },
+        };
+        debug!("version request {:?}", resolver);
+        Ok(resolver.resolve(versions))
+    }
 }
 
 impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
@@ -38,60 +70,35 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
         self.language.name
     }
 
-    fn get_env_hint(&self, current_dir: &Path) -> String {
-        let bin_dir = current_dir.join("current").join("bin");
-        let go_path_dir = current_dir.join("go_path");
-        if cfg!(target_os="windows") {
-            format!(r###"please add `{0:?}` to your path and set GOPATH to `{1:?}`"###, bin_dir, go_path_dir)
-        } else {
-            format!(r###"please add `{0:?}` to your path.
-            for example:
-            export PATH="{0:?}:$PATH"
-            and set GOPATH
-            export GOPATH="{1:?}"
-            "###, bin_dir, go_path_dir)
-        }
-    }
-
     fn list(&self, local_only: bool, context: &'a GoContext) -> Result<Vec<LanguageVersion>> {
+        if context.filter.lts {
+            return Err(LanguageError::General("`--lts` is only supported by node"));
+        }
         let installed_version_map = self.get_installed_versions(context)?;
         if local_only {
-            let installed_versions: Vec<_> = installed_version_map
-                .into_iter()
-                .map(|(k, v)| LanguageVersion {
-                    version: k,
-                    installed: true,
-                    inuse: v,
-                })
-                .collect();
-            return Ok(installed_versions);
+            return self.list_installed(context);
         }
-        let version_maps = self
-            .rt
-            .block_on(html::parse_golang_official(OFFICIAL_URL, &context.proxy))?;
-        let versions: Vec<_> = version_maps
-            .into_iter()
-            .filter_map(|(k, v)| GolangVersion::from_map(k, v))
-            .collect();
-        let filter = match context.filter {
-            Some(v) => v,
-            None => "",
-        };
-
-        let filter_re = regex::RegexBuilder::new(&regex::escape(filter))
-            .case_insensitive(true)
-            .build()
-            .unwrap();
+        let versions = self.get_versions(context)?;
+        let filter = &context.filter;
+        let filter_re = filter.text_regex();
+        let sys_arch = context.arch.to_lowercase();
+        let arch = convert_to_go_arch(sys_arch.as_str());
+        let os = convert_to_go_os(context.os);
 
         let mut set = HashSet::new();
         for version in versions {
-            if filter.is_empty() || version.is_match(&filter_re) {
+            if filter_re.as_ref().is_none_or(|re| version.is_match(re))
+                && (!filter.installable || version.has_matched_package(os.as_str(), arch))
+                && filter.matches_major(&version.sem_version)
+                && filter.stable.is_none_or(|s| s != version.is_unstable())
+            {
                 set.insert(version.sem_version.clone());
             }
         }
 
         let mut result: Vec<Version> = set.into_iter().collect();
         result.sort();
+        let result = filter.keep_latest_per_minor(result, |v| Some((v.major, v.minor)));
         let returned = result
             .into_iter()
             .map(|g| -> LanguageVersion {
@@ -109,47 +116,18 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
     }
 
     fn install(&self, version: String, context: &'a GoContext) -> Result<InstallResult> {
-        let req = match VersionReq::parse(&version) {
-            Ok(r) => r,
-            Err(e) => {
-                return Err(LanguageError::GeneralString(format!("cannot parse the given version({}): {}", version, e)));
-            },
-        };
-        let version_maps = self
-            .rt
-            .block_on(html::parse_golang_official(OFFICIAL_URL, &context.proxy))?;
-        let mut versions: Vec<_> = version_maps
-            .into_iter()
-            .filter_map(|(k, v)| GolangVersion::from_map(k, v))
-            .collect();
-        versions.sort();
+        if context.language_context.as_ref().is_some_and(|c| c.lts) {
+            return Err(LanguageError::General("`--lts` is only supported by node"));
+        }
+        let mirror = Mirror::new(OFFICIAL_URL, context.mirror.as_ref());
+        let versions = self.get_versions(context)?;
 
-        let sys_arch = std::env::consts::ARCH.to_lowercase();
+        let sys_arch = context.arch.to_lowercase();
         let arch = convert_to_go_arch(sys_arch.as_str());
-        let sys_os = std::env::consts::OS.to_lowercase();
+        let sys_os = context.os.to_lowercase();
         let os = convert_to_go_os(sys_os.as_str());
         debug!("current arch:{}, os:{}", arch, os);
-        let mut matched_version: Option<GolangVersion> = None;
-        let mut exact_matched_version: Option<GolangVersion> = None;
-        debug!("version request {}", req);
-        let go_ver = format!("go{}", version);
-        for item in versions.into_iter().rev() {
-            let sem_version = &item.sem_version;
-            // debug!("test with version: {}", version);
-            if item.version == go_ver {
-                exact_matched_version = Some(item);
-                break;
-            }
-            if exact_matched_version.is_none()
-                && matched_version.is_none()
-                && req.matches(sem_version)
-            {
-                matched_version = Some(item);
-            }
-        }
-        if exact_matched_version.is_some() {
-            matched_version = exact_matched_version;
-        }
+        let matched_version = self.find_release(&version, &versions, context)?.cloned();
         debug!("found matched version: {:?}", matched_version);
 
         match matched_version {
@@ -166,7 +144,7 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
                                 let package = match version
                                     .get_matched_package(os.as_str(), arch) {
                                         Some(p) => p,
-                                        None => return Err(LanguageError::NoMatchedArchOrOS(sys_arch, sys_os)),
+                                        None => return Err(LanguageError::no_matched_platform(arch, &os, version.platforms())),
                                     };
                                 let download_dir = context.language_dir.get_tmp_dir();
                                 let downloaded_file = download_dir.join(&package.file_name);
@@ -178,7 +156,7 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
                                         remove_file(&downloaded_file)?;
                                     } else if checksum::verify(checksum::ChecksumMethod::Sha256, &downloaded_file, &package.checksum)?
                                     {
-                                        need_download = true;
+                                        need_download = false;
                                         debug!("file to download exists, use it");
                                     } else {
                                         debug!("file to download exists and the file's checksum is wrong, delete it");
@@ -187,18 +165,19 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
                                 }
                                 let versions = context.language_dir.get_versions_dir();
                                 let archived_dir = versions.join(version_str);
+                                if need_download && context.offline {
+                                    return Err(LanguageError::Offline(package.file_name.clone()));
+                                }
                                 if need_download {
-                                    let mut url = package.url.clone();
-                                    if url.starts_with('/') {
-                                        url = format!("https://go.dev{}", url);
-                                    }
-                                    debug!("download file({}) to {:?}", &url, &downloaded_file);
-                                    self.rt.block_on(http::download_file(
-                                        &url,
+                                    let urls = mirror.urls(&package.file_name);
+                                    debug!("download file({:?}) to {:?}", &urls, &downloaded_file);
+                                    let hasher = self.rt.block_on(http::download_file_from(
+                                        &urls,
                                         &downloaded_file,
                                         &context.proxy,
+                                        &checksum::ChecksumMethod::Sha256,
                                     ))?;
-                                    if !checksum::verify(checksum::ChecksumMethod::Sha256, &downloaded_file, &package.checksum)? {
+                                    if !hasher.verify(&package.checksum) {
                                         error!("failed to verify the downloaded file, delete it");
                                         remove_file(&downloaded_file)?;
                                         return Err(LanguageError::FailedToVerify());
@@ -214,6 +193,9 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
                                 fs::decompress(&downloaded_file, versions)?;
                                 debug!("rename go to version");
                                 rename(&go_dir, &archived_dir)?;
+                                if !context.keep_archives {
+                                    remove_file(&downloaded_file)?;
+                                }
 
                                 let mut need_hint = false;
                                 if !context.language_context.as_ref().is_some_and(|c| c.no_use) {
@@ -227,7 +209,6 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
                                     }
                                     fs::make_link(current_dir, &archived_dir)?;
                                 }
-                                remove_file(downloaded_file)?;
                                 if need_hint {
                                     Ok(InstallResult::SuccessNeedHint)
                                 } else {
@@ -238,19 +219,88 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for GolangExecutor {
                         Err(_) => Err(LanguageError::FailedToReadFS()),
                     }
                 } else {
-                    Err(LanguageError::NoMatchedArchOrOS(
-                        arch.to_string(),
-                        os.to_string(),
-                    ))
+                    Err(LanguageError::no_matched_platform(arch, &os, version.platforms()))
                 }
             }
             None => Err(LanguageError::NoSuchVersion(version)),
         }
     }
 
-    fn post_venv(&self, dir: &Path) -> Result<()> {
-        generate_scripts(dir)?;
-        Ok(())
+    /// resolve `latest` and `stable` to the latest installed stable release.
+    fn resolve_installed_version(&self, version: String, context: &GoContext) -> Result<String> {
+        if !LATEST_ALIASES.contains(&version.to_lowercase().as_str()) {
+            return Ok(version);
+        }
+        let latest = self
+            .get_installed_versions(context)?
+            .into_keys()
+            .filter_map(|n| Version::parse(&n).ok())
+            .filter(|v| v.pre.is_empty())
+            .max();
+        Ok(latest.map(|v| format_semver(&v)).unwrap_or(version))
+    }
+
+    fn list_releases(&self, context: &GoContext) -> Result<Vec<String>> {
+        let sys_arch = context.arch.to_lowercase();
+        let arch = convert_to_go_arch(sys_arch.as_str());
+        let os = convert_to_go_os(context.os);
+        let result = self
+            .get_versions(context)?
+            .into_iter()
+            .filter(|v| !v.is_unstable() && v.has_matched_package(os.as_str(), arch))
+            .map(|v| format_semver(&v.sem_version))
+            .collect();
+        Ok(result)
+    }
+
+    fn info(&self, version: String, context: &GoContext) -> Result<ReleaseInfo> {
+        let versions = self.get_versions(context)?;
+        let release = match self.find_release(&version, &versions, context)? {
+            Some(r) => r,
+            None => return Err(LanguageError::NoSuchVersion(version)),
+        };
+        let name = format_semver(&release.sem_version);
+        let version_type = release.packages.first().map(|p| p.version_type.clone()).unwrap_or_default();
+        let packages = release
+            .packages
+            .iter()
+            .map(|p| {
+                // source packages have no os and arch
+                let platform = if p.os.is_empty() { "any".to_string() } else { format!("{}/{}", p.os, p.arch).to_lowercase() };
+                let mut details = vec![p.file_name.clone(), p.size.clone()];
+                if !p.checksum.is_empty() {
+                    details.push(format!("sha256:{}", p.checksum));
+                }
+                PackageInfo {
+                    platform,
+                    kind: p.kind.to_lowercase(),
+                    details,
+                }
+            })
+            .collect();
+        Ok(ReleaseInfo {
+            installed_dir: self.get_installed_dir(&name, context)?,
+            name,
+            details: vec![("type", version_type)],
+            packages,
+        })
+    }
+
+    fn get_env(&self, home_dir: &Path, version_dir: &Path) -> LanguageEnv {
+        LanguageEnv {
+            paths: vec![self.get_bin_dir(version_dir)],
+            vars: vec![("GOROOT", version_dir.to_path_buf()), ("GOPATH", home_dir.join("go_path"))],
+        }
+    }
+
+    fn get_project_version(&self, current_dir: &Path) -> Result<Option<ProjectVersion>> {
+        match find_go_mod(current_dir)? {
+            Some((path, go_mod)) => {
+                debug!("found {:?}: {:?}", &path, &go_mod);
+                Ok(go_mod.to_project_version(path))
+            }
+            None => Ok(None),
+        }
     }
 }
 
@@ -275,6 +325,13 @@ mod test {
     use semver::VersionReq;
 
     use super::super::version::parse_semver;
+    use super::super::super::language_executor::{ExecutorContext, LanguageExecutor, ListFilter};
+    use super::GolangExecutor;
+    use crate::tool::cache::IndexCache;
+    use crate::tool::fs::LanguageDir;
+    use crate::tool::test_dir::TestDir;
+    use std::collections::BTreeMap;
+    use std::fs;
 
     #[test]
     fn test_file_regex() {
@@ -324,4 +381,61 @@ mod test {
         let v2 = parse_semver(&String::from("1.1.6")).expect("");
         assert!(!req.matches(&v2));
     }
+
+    #[test]
+    fn test_find_upgrade() {
+        let executor = GolangExecutor::new();
+        let releases: Vec<_> = ["1.21.5", "1.21.6", "1.22.0", "1.22.1", "1.23.0-rc2"]
+            .iter()
+            .filter_map(|v| executor.parse_installed(v.to_string()))
+            .collect();
+        let upgrade = |v: &str| {
+            let installed = executor.parse_installed(v.to_string()).unwrap();
+            executor.find_upgrade(&installed, &releases).map(|r| r.name.clone())
+        };
+        assert_eq!(Some("1.21.6".to_string()), upgrade("1.21.1"));
+        assert_eq!(None, upgrade("1.21.6"));
+        assert_eq!(Some("1.22.1".to_string()), upgrade("1.22.0"));
+        assert_eq!(None, upgrade("1.23.0-rc1"));
+    }
+
+    #[test]
+    fn test_resolve_installed() {
+        let test_dir = TestDir::new("go_resolve_installed");
+        let root = test_dir.path();
+        let language_dir = LanguageDir::new(root, "go").unwrap();
+        for name in ["1.20.0", "1.20.14", "1.21.1", "1.21.6"] {
+            fs::create_dir_all(language_dir.get_versions_dir().join(name)).unwrap();
+        }
+        let context = ExecutorContext {
+            proxy: None,
+            mirror: None,
+            language_dir: &language_dir,
+            shims_dir: root,
+            language_context: None,
+            filter: ListFilter::default(),
+            aliases: BTreeMap::new(),
+            index_cache: IndexCache::new(root, 0),
+            offline: true,
+            keep_archives: false,
+            arch: "x86_64",
+            os: "linux",
+        };
+        let executor = GolangExecutor::new();
+        executor.select("1.20".to_string(), &context).unwrap();
+        assert_eq!(Some("1.20.14".to_string()), executor.get_current_version(&context));
+        executor.select("~1.21.0".to_string(), &context).unwrap();
+        assert_eq!(Some("1.21.6".to_string()), executor.get_current_version(&context));
+
+        let venv_dir = root.join("venv");
+        executor.venv("1.20.0".to_string(), venv_dir.to_string_lossy().to_string(), &context).unwrap();
+        assert_eq!(Some("1.20.0".to_string()), executor.get_linked_name(&venv_dir.join("go")));
+
+        assert!(executor.uninstall("1.20".to_string(), &context).is_err());
+        executor.uninstall("1.21.1".to_string(), &context).unwrap();
+        assert!(!language_dir.get_versions_dir().join("1.21.1").exists());
+        executor.uninstall("1.21".to_string(), &context).unwrap();
+        assert!(!language_dir.get_versions_dir().join("1.21.6").exists());
+        assert_eq!(None, executor.get_current_version(&context));
+    }
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::tool::cache::IndexCache;
 use crate::tool::http;
 use scraper::{ElementRef, Html, Selector};
 
@@ -20,12 +21,25 @@ pub static TYPE_ARCHIVE: &str = "archive";
 pub type Result<T> = std::result::Result<T, http::HttpError>;
 
 pub async fn parse_golang_official(
-    url: &'static str,
+    cache: &IndexCache,
+    urls: &[String],
     proxy: &Option<String>,
 ) -> Result<HashMap<String, Vec<HashMap<&'static str, String>>>> {
-    let content = http::download_html(&url.to_string(), proxy).await?;
-    // println!("golang official {}", content);
-    let document = Html::parse_document(&content);
+    let versions = cache
+        .download_html_from(urls, proxy, |content| {
+            let versions = parse_golang_page(content);
+            if versions.is_empty() {
+                Err("no release is found")
+            } else {
+                Ok(versions)
+            }
+        })
+        .await?;
+    Ok(versions)
+}
+
+fn parse_golang_page(content: &str) -> HashMap<String, Vec<HashMap<&'static str, String>>> {
+    let document = Html::parse_document(content);
     let stable_selector = Selector::parse("#stable ~ div[id^=\"go\"]").unwrap();
     let unstable_selector = Selector::parse("#unstable ~ div[id^=\"go\"]").unwrap();
     let archive_selector = Selector::parse("#archive div[id^=\"go\"]").unwrap();
@@ -42,7 +56,7 @@ pub async fn parse_golang_official(
     }
     stable_versions.extend(unstable_versions);
     stable_versions.extend(archive_versions);
-    Ok(stable_versions)
+    stable_versions
 }
 
 fn parse_golang_official_table(
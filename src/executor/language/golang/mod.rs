@@ -1,6 +1,6 @@
-mod script;
 mod version;
 mod golang_executor;
 mod html;
+mod go_mod;
 
 pub use golang_executor::GolangExecutor;
\ No newline at end of file
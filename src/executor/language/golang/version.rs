@@ -1,6 +1,8 @@
 use super::html::{
-    KEY_ARCH, KEY_CHECKSUM, KEY_FILE_NAME, KEY_KIND, KEY_OS, KEY_SIZE, KEY_TYPE, KEY_URL,
+    KEY_ARCH, KEY_CHECKSUM, KEY_FILE_NAME, KEY_KIND, KEY_OS, KEY_SIZE, KEY_TYPE, KEY_URL, TYPE_UNSTABLE,
 };
+use super::super::language_executor::archive_platforms;
+use super::super::resolver::ResolvableVersion;
 use crate::tool::logger::debug;
 use semver::{BuildMetadata, Prerelease, Version};
 use std::{cmp::Ordering, collections::HashMap};
@@ -31,6 +33,11 @@ impl GolangVersion {
         false
     }
 
+    /// pre-releases like `1.22rc1`, and versions listed in the unstable section.
+    pub fn is_unstable(&self) -> bool {
+        !self.sem_version.pre.is_empty() || self.packages.iter().any(|p| p.version_type == TYPE_UNSTABLE)
+    }
+
     pub fn has_matched_package(&self, os: &str, arch: &str) -> bool {
         debug!("has mached package with os({}) and arch({})", os, arch);
         for package in &self.packages {
@@ -45,6 +52,11 @@ impl GolangVersion {
         false
     }
 
+    /// platforms which have an archive package, like `linux/x64`.
+    pub fn platforms(&self) -> Vec<String> {
+        archive_platforms(self.packages.iter().map(|p| (p.os.as_str(), p.arch.as_str(), p.kind.to_lowercase() == "archive")))
+    }
+
     pub fn get_matched_package(&self, os: &str, arch: &str) -> Option<&GolangPackage> {
         self.packages.iter().find(|v| {
             v.arch.to_lowercase() == arch
@@ -54,9 +66,21 @@ impl GolangVersion {
     }
 }
 
+impl ResolvableVersion for GolangVersion {
+    fn is_named(&self, spec: &str) -> bool {
+        self.version.strip_prefix("go").unwrap_or(&self.version) == spec.strip_prefix("go").unwrap_or(spec)
+    }
+
+    fn sem_version(&self) -> &Version {
+        &self.sem_version
+    }
+}
+
 impl Ord for GolangVersion {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.version.cmp(&other.version)
+        self.sem_version
+            .cmp(&other.sem_version)
+            .then_with(|| self.version.cmp(&other.version))
     }
 }
 
@@ -68,13 +92,14 @@ impl PartialOrd<GolangVersion> for GolangVersion {
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct GolangPackage {
-    version_type: String,
+    /// stable, unstable or archive, i.e. the section of the download page.
+    pub version_type: String,
     pub file_name: String,
-    os: String,
-    kind: String,
-    arch: String,
+    pub os: String,
+    pub kind: String,
+    pub arch: String,
     pub checksum: String,
-    size: String,
+    pub size: String,
     pub url: String,
 }
 
@@ -123,9 +148,17 @@ pub fn parse_semver(version: &str) -> Option<Version> {
 
 #[cfg(test)]
 mod test {
-    use super::parse_semver;
+    use super::super::super::resolver::VersionResolver;
+    use super::{parse_semver, GolangVersion};
     use semver::{BuildMetadata, Prerelease, Version};
 
+    fn versions(names: &[&str]) -> Vec<GolangVersion> {
+        names
+            .iter()
+            .map(|n| GolangVersion::from_map(n.to_string(), vec![]).unwrap())
+            .collect()
+    }
+
     #[test]
     fn test_parse_semver() {
         assert_eq!(
@@ -159,4 +192,40 @@ mod test {
             parse_semver("1.1rc1").expect("wrong")
         );
     }
+
+    #[test]
+    fn test_order() {
+        let mut vs = versions(&["go1.21.0", "go1.9", "go1.21rc1", "go1.20.14"]);
+        vs.sort();
+        let names: Vec<_> = vs.iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(vec!["go1.9", "go1.20.14", "go1.21rc1", "go1.21.0"], names);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let vs = versions(&["go1.9", "go1.20", "go1.20.14", "go1.21rc1", "go1.21.0", "go1.21.6", "go1.22rc1"]);
+        let cases = [
+            // (spec, prerelease, expected)
+            ("1.20", false, Some("go1.20.14")),
+            ("go1.20", false, Some("go1.20")),
+            ("1.20.0", false, Some("go1.20")),
+            ("1.21", false, Some("go1.21.6")),
+            ("~1.20.0", false, Some("go1.20.14")),
+            ("1.21rc1", false, Some("go1.21rc1")),
+            ("go1.21rc1", false, Some("go1.21rc1")),
+            ("*", false, Some("go1.21.6")),
+            ("*", true, Some("go1.22rc1")),
+            ("1.22", false, None),
+            ("1.22", true, Some("go1.22rc1")),
+            ("~1.9", false, Some("go1.9")),
+        ];
+        for (spec, pre, expected) in cases {
+            let resolved = VersionResolver::parse(spec, parse_semver)
+                .unwrap()
+                .with_prerelease(pre)
+                .resolve(&vs)
+                .map(|v| v.version.as_str());
+            assert_eq!(expected, resolved, "spec: {}", spec);
+        }
+    }
 }
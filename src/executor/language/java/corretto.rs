@@ -2,21 +2,28 @@ use super::{
     version::{JavaPackage, JavaVersion, PackageKind},
     super::language_executor::Result,
 };
+use crate::tool::cache::IndexCache;
 use log::debug;
-use octocrab::{self, params};
+use serde::Deserialize;
 
-pub async fn parse_corretto() -> Result<Vec<JavaVersion>> {
-    let client = octocrab::instance();
-    let page = client
-        .orgs("corretto")
-        .list_repos()
-        .repo_type(params::repos::Type::Sources)
-        .per_page(50)
-        .send()
-        .await?;
+#[derive(Deserialize, Debug)]
+struct Repository {
+    name: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Release {
+    body: Option<String>,
+}
+
+/// releases are listed by GitHub API from `site`, i.e. `https://api.github.com/`.
+pub async fn parse_corretto(cache: &IndexCache, site: &str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
+    let repos_url = format!("{}orgs/corretto/repos?type=sources&per_page=50", site);
+    let content = cache.download_html(&repos_url, proxy).await?;
+    let page: Vec<Repository> = serde_json::from_str(&content)?;
     // debug!("repos: {:?}", page);
     let name_re = regex::Regex::new(r"^corretto-(?<version>\d+)$").unwrap();
-    let repositories: Vec<_> = page.items.iter().filter_map(|r| {
+    let repositories: Vec<_> = page.iter().filter_map(|r| {
         match name_re.captures(&r.name) {
             Some(caps) => {
                 caps.get(0).map(|v| v.as_str())
@@ -28,8 +35,10 @@ pub async fn parse_corretto() -> Result<Vec<JavaVersion>> {
 
     let mut result = Vec::new();
     for repository in repositories {
-        let release_result = client.repos("corretto", repository).releases().list().per_page(100).send().await?;
-        let mut versions: Vec<_> = release_result.items.iter().filter_map(|item|{
+        let releases_url = format!("{}repos/corretto/{}/releases?per_page=100", site, repository);
+        let content = cache.download_html(&releases_url, proxy).await?;
+        let release_result: Vec<Release> = serde_json::from_str(&content)?;
+        let mut versions: Vec<_> = release_result.iter().filter_map(|item|{
             match &item.body {
                 Some(body) => parse_table(body.as_str()),
                 None => None,
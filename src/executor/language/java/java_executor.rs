@@ -1,26 +1,40 @@
 use super::super::language_executor::{
-    ExecutorContext, LanguageContext, LanguageError, LanguageExecutor, LanguageVersion, Result,
+    ExecutorContext, LanguageContext, LanguageEnv, LanguageError, LanguageExecutor,
+    LanguageVersion, PackageInfo, ProjectVersion, ReleaseInfo, Result,
 };
-use super::scripts::generate_scripts;
+use super::project::find_java_project;
 use super::vendor::Vendor;
-use super::version::JavaVersion;
+use super::super::resolver::VersionResolver;
+use super::version::{parse_semver, JavaVersion};
 use crate::executor::language::language_executor::InstallResult;
 use crate::tool::checksum;
 use crate::tool::logger::{debug, error, info};
+use crate::tool::mirror::Mirror;
+use crate::tool::http::HttpError;
 use crate::tool::{fs, http, SupportedLanguage, JAVA};
-use semver::VersionReq;
+use semver::Version;
 use std::fs::{remove_file, rename};
 use std::path::Path;
 use tokio::runtime;
 
 pub struct JavaLanguageContext {
-    pub vendor: String,
+    /// given by `--vendor` option.
+    pub vendor: Option<String>,
+    /// given by `java.default_vendor` option, used if `vendor` is not given.
+    pub default_vendor: Option<String>,
     /// used in `install` command, whether to use installed version instantly.
     pub no_use: bool
 }
 
 impl LanguageContext for JavaLanguageContext {}
 
+impl JavaLanguageContext {
+    /// `--vendor`, or `java.default_vendor` if it's not given.
+    pub fn get_vendor(&self) -> Option<&String> {
+        self.vendor.as_ref().or(self.default_vendor.as_ref())
+    }
+}
+
 type JavaContext<'a> = ExecutorContext<'a, JavaLanguageContext>;
 
 pub struct JavaExecutor {
@@ -37,6 +51,22 @@ impl<'a> JavaExecutor {
         JavaExecutor { language: JAVA, rt }
     }
 
+    /// get all releases of `vendor`.
+    fn get_versions(&self, vendor: &Vendor, context: &JavaContext) -> Result<Vec<JavaVersion>> {
+        match self.rt.block_on(vendor.get_versions(&context.index_cache, &context.proxy)) {
+            Ok(Some(v)) => Ok(v),
+            Ok(None) => {
+                error!("Unsupported vendor");
+                Err(LanguageError::Html())
+            }
+            Err(e @ LanguageError::Http(HttpError::NotCached(_))) => Err(e),
+            Err(e) => {
+                error!("failed to parse java version list: {}", e);
+                Err(LanguageError::Html())
+            }
+        }
+    }
+
     fn find_version_install(
         &self,
         version: String,
@@ -62,10 +92,7 @@ impl<'a> JavaExecutor {
                         Err(_) => Err(LanguageError::FailedToReadFS()),
                     }
                 } else {
-                    Err(LanguageError::NoMatchedArchOrOS(
-                        arch.to_string(),
-                        os.to_string(),
-                    ))
+                    Err(LanguageError::no_matched_platform(arch, os, version.platforms()))
                 }
             }
             None => Err(LanguageError::NoSuchVersion(version)),
@@ -78,79 +105,31 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
         self.language.name
     }
 
-    fn get_env_hint(&self, current_dir: &Path) -> String {
-        let current_dir = current_dir.join("current");
-        let bin_dir = current_dir.join("bin");
-        if cfg!(target_os="windows") {
-            format!(r###"please add `{0:?}` to your path and set JAVA_HOME to `{1:?}`"###, bin_dir, current_dir)
-        } else {
-            format!(r###"please add `{0:?}` to your path.
-            for example:
-            export PATH="{0:?}:$PATH"
-            and set JAVA_HOME
-            export JAVA_HOME="{1:?}"
-            "###, bin_dir, current_dir)
-        }
-    }
-
     fn list(&self, local_only: bool, context: &'a JavaContext<'a>) -> Result<Vec<LanguageVersion>> {
         let installed_version_map = self.get_installed_versions(context)?;
         if local_only {
-            let installed_versions: Vec<_> = installed_version_map
-                .into_iter()
-                .map(|(k, v)| LanguageVersion {
-                    version: k,
-                    installed: true,
-                    inuse: v,
-                })
-                .collect();
-            return Ok(installed_versions);
+            return self.list_installed(context);
         }
 
-        let language_context = match context.language_context.as_ref() {
-            Some(l) => l,
-            None => {
-                return Err(LanguageError::General("Java vendor is required to list remote versions, valid vendors include openjdk, corretto"));
-            }
-        };
-
-        let vendor_arg = &language_context.vendor.to_lowercase();
-        let vendor = match Vendor::from_str(vendor_arg.as_str()) {
-            Some(v) => v,
-            None => {
-                return Err(LanguageError::General("Java vendor is required to list remote versions, valid vendors include openjdk, corretto"))
-            },
-        };
-
-        let version_items: Vec<JavaVersion> =
-            match self.rt.block_on(vendor.get_versions(&context.proxy)) {
-                Ok(vs) => match vs {
-                    Some(v) => v,
-                    None => {
-                        error!("Unsupported vendor");
-                        return Err(LanguageError::Html());
-                    }
-                },
-                Err(e) => {
-                    error!("failed to parse node version list: {}", e);
-                    return Err(LanguageError::Html());
-                }
-            };
+        let vendor = get_vendor(context)?;
+        let version_items = self.get_versions(vendor, context)?;
 
-        let filter = match context.filter {
-            Some(v) => v,
-            None => "",
-        };
-        let filter_re = regex::RegexBuilder::new(&regex::escape(filter))
-            .case_insensitive(true)
-            .build()
-            .unwrap();
+        let filter = &context.filter;
+        let filter_re = filter.text_regex();
+        let sys_arch = context.arch.to_lowercase();
+        let arch = convert_to_java_arch(sys_arch.as_str());
+        let os = convert_to_java_os(context.os);
 
         let mut versions: Vec<JavaVersion> = version_items
             .into_iter()
-            .filter(|o| filter.is_empty() || o.is_match(&filter_re))
+            .filter(|v| {
+                filter_re.as_ref().is_none_or(|re| v.is_match(re))
+                    && filter.matches_major(&v.sem_version)
+                    && (!filter.installable || v.has_matched_package(os, arch))
+            })
             .collect();
         versions.sort();
+        let versions = filter.keep_latest_per_minor(versions, |v| Some((v.sem_version.major, v.sem_version.minor)));
 
         let returned: Vec<LanguageVersion> = versions
             .into_iter()
@@ -169,66 +148,15 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
     }
 
     fn install(&self, version: String, context: &'a JavaContext<'a>) -> Result<InstallResult> {
-        let language_context = context.language_context.as_ref();
-        let vendor_arg = &language_context.expect("Java vendor is required to install command, valid vendors include openjdk, corretto").vendor.to_lowercase();
-        let vendor = Vendor::from_str(vendor_arg.as_str()).expect(
-            "Java vendor is required to install command, valid vendors include openjdk, corretto",
-        );
-
-        let trimed_version = vendor.trim(&version);
-        let parsed_version = match vendor.parse_version(&trimed_version) {
-            Some(v) => v,
-            None => return Err(LanguageError::GeneralString(format!("Version({}) is not valid for {}", version, vendor.name))),
-        };
-        let req = match VersionReq::parse(&parsed_version) {
-            Ok(r) => r,
-            Err(e) => {
-                return Err(LanguageError::GeneralString(format!("cannot parse the given version({}): {}", version, e)));
-            },
-        };
-        let version_items: Vec<JavaVersion> =
-            match self.rt.block_on(vendor.get_versions(&context.proxy)) {
-                Ok(vs) => match vs {
-                    Some(v) => v,
-                    None => {
-                        error!("Unsupported vendor");
-                        return Err(LanguageError::Html());
-                    }
-                },
-                Err(e) => {
-                    error!("failed to parse node version list: {}", e);
-                    return Err(LanguageError::Html());
-                }
-            };
-
-        let mut versions: Vec<JavaVersion> = version_items;
-        versions.sort();
+        let vendor = get_vendor_of(&version, context)?;
+        let versions = self.get_versions(vendor, context)?;
 
-        let sys_arch = std::env::consts::ARCH.to_lowercase();
+        let sys_arch = context.arch.to_lowercase();
         let arch = convert_to_java_arch(sys_arch.as_str());
-        let sys_os = std::env::consts::OS.to_lowercase();
+        let sys_os = context.os.to_lowercase();
         let os = convert_to_java_os(sys_os.as_str());
         debug!("current arch:{}, os:{}", arch, os);
-        let mut matched_version: Option<JavaVersion> = None;
-        let mut exact_matched_version: Option<JavaVersion> = None;
-        debug!("version request {}", req);
-        for item in versions.into_iter().rev() {
-            // debug!("test with version: {}", version);
-            if item.version == trimed_version {
-                exact_matched_version = Some(item);
-                break;
-            }
-            let sem_version = &item.sem_version;
-            if exact_matched_version.is_none()
-                && matched_version.is_none()
-                && req.matches(sem_version)
-            {
-                matched_version = Some(item);
-            }
-        }
-        if exact_matched_version.is_some() {
-            matched_version = exact_matched_version;
-        }
+        let matched_version = find_release(vendor, &version, &versions)?.cloned();
         debug!("found matched version: {:?}", matched_version);
 
         match self.find_version_install(version, context, matched_version, os, arch) {
@@ -237,8 +165,9 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
                 let package = match version
                     .get_matched_package(os, arch) {
                         Some(p) => p,
-                        None => return Err(LanguageError::NoMatchedArchOrOS(sys_arch, sys_os)),
+                        None => return Err(LanguageError::no_matched_platform(arch, os, version.platforms())),
                     };
+                let mirror = Mirror::new(vendor.download_url, context.mirror.as_ref());
                 let download_dir = context.language_dir.get_tmp_dir();
                 let segments: Vec<&str> = package.url.split('/').collect();
                 let file_name = segments[segments.len() - 1];
@@ -256,7 +185,8 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
                     checksum = v.clone();
                 } else if let Some(v) = package.checksum_url.as_ref() {
                     checksum_method = checksum::ChecksumMethod::Sha256;
-                    checksum = self.rt.block_on(http::download_html(v, &context.proxy))?;
+                    // checksums come from the vendor, so archives from a mirror are verified against them
+                    checksum = self.rt.block_on(context.index_cache.download_html(v, &context.proxy))?;
                 }
 
                 let mut need_download = true;
@@ -279,16 +209,21 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
 
                 let versions = context.language_dir.get_versions_dir();
                 let archived_dir = versions.join(version_str);
+                if need_download && context.offline {
+                    return Err(LanguageError::Offline(file_name.to_string()));
+                }
                 if need_download {
-                    debug!("download file({}) to {:?}", &package.url, &downloaded_file);
-                    self.rt.block_on(http::download_file(
-                        &package.url,
+                    let urls = mirror.rewrite(&package.url);
+                    debug!("download file({:?}) to {:?}", &urls, &downloaded_file);
+                    let hasher = self.rt.block_on(http::download_file_from(
+                        &urls,
                         &downloaded_file,
                         &context.proxy,
+                        &checksum_method,
                     ))?;
                     if checksum.is_empty() || checksum_method == checksum::ChecksumMethod::None {
                         debug!("no checksum with downloaded file, use it");
-                    } else if !checksum::verify(checksum_method, &downloaded_file, &checksum)? {
+                    } else if !hasher.verify(&checksum) {
                         error!("failed to verify download file, delete it");
                         remove_file(&downloaded_file)?;
                         return Err(LanguageError::FailedToVerify());
@@ -317,6 +252,9 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
 
                 debug!("rename folder {:?} to version", &node_dir);
                 rename(&node_dir, &archived_dir)?;
+                if !context.keep_archives {
+                    remove_file(&downloaded_file)?;
+                }
 
                 let mut need_hint = false;
                 if !context.language_context.as_ref().is_some_and(|c| c.no_use) {
@@ -330,7 +268,6 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
                     }
                     fs::make_link(current_dir, &archived_dir)?;
                 }
-                remove_file(downloaded_file)?;
                 if need_hint {
                     Ok(InstallResult::SuccessNeedHint)
                 } else {
@@ -341,9 +278,193 @@ impl<'a> LanguageExecutor<'a, JavaLanguageContext> for JavaExecutor {
         }
     }
 
-    fn post_venv(&self, dir: &Path) -> Result<()> {
-        generate_scripts(dir)?;
-        Ok(())
+    /// releases of the given vendor, or of vendors of installed versions if it's not given.
+    fn list_releases(&self, context: &JavaContext<'a>) -> Result<Vec<String>> {
+        let vendors: Vec<&Vendor> = if context.language_context.as_ref().is_some_and(|c| c.get_vendor().is_some()) {
+            vec![get_vendor(context)?]
+        } else {
+            let installed_versions = self.get_installed_versions(context)?;
+            Vendor::all()
+                .iter()
+                .copied()
+                .filter(|v| installed_versions.keys().any(|n| n.starts_with(&format!("{}-", v.name))))
+                .collect()
+        };
+        let sys_arch = context.arch.to_lowercase();
+        let arch = convert_to_java_arch(sys_arch.as_str());
+        let os = convert_to_java_os(context.os);
+        let mut result = Vec::new();
+        for vendor in vendors {
+            let versions = self.get_versions(vendor, context)?;
+            result.extend(
+                versions
+                    .iter()
+                    .filter(|v| v.sem_version.pre.is_empty() && v.has_matched_package(os, arch))
+                    .map(|v| format_installed_version(vendor, v)),
+            );
+        }
+        Ok(result)
+    }
+
+    /// newer releases of the same major version are upgrades, e.g. `17.0.10` of `17.0.9`.
+    fn release_line(&self, version: &Version) -> (u64, u64) {
+        (version.major, 0)
+    }
+
+    fn info(&self, version: String, context: &JavaContext<'a>) -> Result<ReleaseInfo> {
+        let vendor = get_vendor_of(&version, context)?;
+        let versions = self.get_versions(vendor, context)?;
+        let release = match find_release(vendor, &version, &versions)? {
+            Some(r) => r,
+            None => return Err(LanguageError::NoSuchVersion(version)),
+        };
+        let name = format_installed_version(vendor, release);
+        let packages = release
+            .packages
+            .iter()
+            .map(|p| {
+                let file_name = p.url.rsplit('/').next().unwrap_or(&p.url).to_string();
+                let checksum = if let Some(v) = p.checksum_md5.as_ref() {
+                    format!("md5:{}", v)
+                } else if let Some(v) = p.checksum_sha256.as_ref() {
+                    format!("sha256:{}", v)
+                } else if let Some(v) = p.checksum_url.as_ref() {
+                    format!("sha256 from {}", v)
+                } else {
+                    "no checksum".to_string()
+                };
+                PackageInfo {
+                    platform: format!("{}/{}", p.os, p.arch).to_lowercase(),
+                    kind: p.kind.to_str().to_string(),
+                    details: vec![file_name, checksum],
+                }
+            })
+            .collect();
+        Ok(ReleaseInfo {
+            installed_dir: self.get_installed_dir(&name, context)?,
+            name,
+            details: vec![("vendor", vendor.name.to_string())],
+            packages,
+        })
+    }
+
+    fn get_version_of_installed<'b>(&self, name: &'b str) -> &'b str {
+        Vendor::all()
+            .iter()
+            .find_map(|v| name.strip_prefix(v.name).and_then(|n| n.strip_prefix('-')))
+            .unwrap_or(name)
+    }
+
+    fn parse_installed_version(&self, name: &str) -> Option<Version> {
+        parse_semver(self.get_version_of_installed(name))
+    }
+
+    fn get_env(&self, _home_dir: &Path, version_dir: &Path) -> LanguageEnv {
+        LanguageEnv {
+            paths: vec![self.get_bin_dir(version_dir)],
+            vars: vec![("JAVA_HOME", version_dir.to_path_buf())],
+        }
+    }
+
+    fn get_project_version(&self, current_dir: &Path) -> Result<Option<ProjectVersion>> {
+        find_java_project(current_dir)
+    }
+
+    /// only versions of the given vendor are matched if there is one.
+    fn match_installed_req(&self, project: &ProjectVersion, context: &JavaContext<'a>) -> Result<Option<String>> {
+        let prefix = match context.language_context.as_ref().and_then(|c| c.get_vendor()) {
+            Some(_) => Some(format!("{}-", get_vendor(context)?.name)),
+            None => None,
+        };
+        let installed_versions = self.get_installed_versions(context)?;
+        let result = installed_versions
+            .into_keys()
+            .filter(|n| prefix.as_ref().is_none_or(|p| n.starts_with(p)))
+            .filter_map(|n| {
+                let v = self.parse_installed_version(&n)?;
+                project.matches(&v).then_some((v, n))
+            })
+            .max()
+            .map(|(_, n)| n);
+        Ok(result)
+    }
+
+    /// installed versions are named as `vendor-version`, e.g. `corretto-21.0.1.12.1`,
+    /// the vendor is inferred from installed versions if it's not given.
+    fn resolve_installed_version(&self, version: String, context: &JavaContext<'a>) -> Result<String> {
+        check_vendor_of(&version, context)?;
+        if context.language_context.as_ref().and_then(|c| c.get_vendor()).is_some() {
+            let vendor = get_vendor(context)?;
+            if version.starts_with(&format!("{}-", vendor.name)) {
+                return Ok(version);
+            }
+            return Ok(format!("{}-{}", vendor.name, version));
+        }
+        let installed_versions = self.get_installed_versions(context)?;
+        if installed_versions.contains_key(&version) {
+            return Ok(version);
+        }
+        let mut candidates: Vec<_> = Vendor::all()
+            .iter()
+            .map(|v| format!("{}-{}", v.name, version))
+            .filter(|name| installed_versions.contains_key(name))
+            .collect();
+        match candidates.len() {
+            0 => Ok(version),
+            1 => Ok(candidates.remove(0)),
+            _ => Err(LanguageError::AmbiguousVersion(version, candidates.join(", "))),
+        }
+    }
+}
+
+/// get vendor from the prefix of `version` named as installed versions, e.g. `corretto-21.0.1.12.1`,
+/// or from `--vendor` or `java.default_vendor` option.
+fn get_vendor_of(version: &str, context: &JavaContext) -> Result<&'static Vendor> {
+    check_vendor_of(version, context)?;
+    match get_vendor_prefix(version) {
+        Some(v) => Ok(v),
+        None => get_vendor(context),
+    }
+}
+
+/// get vendor from the prefix of `version`, e.g. `corretto` of `corretto-21.0.1.12.1`.
+fn get_vendor_prefix(version: &str) -> Option<&'static Vendor> {
+    Vendor::all().iter().copied().find(|v| version.starts_with(&format!("{}-", v.name)))
+}
+
+/// `--vendor` must be the vendor of `version` if `version` has a vendor prefix.
+fn check_vendor_of(version: &str, context: &JavaContext) -> Result<()> {
+    let vendor = context.language_context.as_ref().and_then(|c| c.vendor.as_ref());
+    match (vendor, get_vendor_prefix(version)) {
+        (Some(vendor), Some(prefix)) if !vendor.eq_ignore_ascii_case(prefix.name) => Err(LanguageError::GeneralString(
+            format!("version {} is not of vendor {}, please remove `--vendor` or the vendor prefix", version, vendor),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// find the release of `vendor` matched by `version` given by user.
+fn find_release<'v>(vendor: &Vendor, version: &str, versions: &'v [JavaVersion]) -> Result<Option<&'v JavaVersion>> {
+    let trimed_version = vendor.trim(version);
+    let resolver = match VersionResolver::parse(trimed_version, |s| {
+        vendor.parse_version(s).and_then(|v| parse_semver(&v))
+    }) {
+        Some(r) => r,
+        None => return Err(LanguageError::GeneralString(format!("Version({}) is not valid for {}", version, vendor.name))),
+    };
+    debug!("version request {:?}", resolver);
+    Ok(resolver.resolve(versions))
+}
+
+/// get vendor from `--vendor` or `java.default_vendor` option.
+fn get_vendor(context: &JavaContext) -> Result<&'static Vendor> {
+    let vendor_arg = match context.language_context.as_ref().and_then(|c| c.get_vendor()) {
+        Some(v) => v.to_lowercase(),
+        None => return Err(LanguageError::General("Java vendor is required, please use `--vendor` or set `java.default_vendor`, valid vendors include openjdk, corretto")),
+    };
+    match Vendor::from_str(vendor_arg.as_str()) {
+        Some(v) => Ok(v),
+        None => Err(LanguageError::GeneralString(format!("Java vendor {} is not supported, valid vendors include openjdk, corretto", vendor_arg))),
     }
 }
 
@@ -369,3 +490,92 @@ fn convert_to_java_arch(arch: &str) -> &str {
 pub fn format_installed_version(vendor: &Vendor, version: &JavaVersion) -> String {
     format!("{}-{}", vendor.name, version.version)
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::super::language_executor::{ExecutorContext, LanguageExecutor, ListFilter};
+    use super::super::vendor::{CORRETTO, OPENJDK};
+    use super::super::version::{parse_semver, JavaVersion};
+    use super::{find_release, JavaExecutor, JavaLanguageContext};
+    use crate::tool::cache::IndexCache;
+    use crate::tool::fs::LanguageDir;
+    use crate::tool::test_dir::TestDir;
+    use std::collections::BTreeMap;
+    use std::fs;
+
+    #[test]
+    fn test_match_installed_versions() {
+        let test_dir = TestDir::new("java_installed");
+        let root = test_dir.path();
+        let language_dir = LanguageDir::new(root, "java").unwrap();
+        for name in ["corretto-17.0.9.8.1", "corretto-17.0.10.7.1", "openjdk-17.0.2", "openjdk-21.0.1"] {
+            fs::create_dir_all(language_dir.get_versions_dir().join(name)).unwrap();
+        }
+        let mut context = ExecutorContext {
+            proxy: None,
+            mirror: None,
+            language_dir: &language_dir,
+            shims_dir: root,
+            language_context: None,
+            filter: ListFilter::default(),
+            aliases: BTreeMap::new(),
+            index_cache: IndexCache::new(root, 0),
+            offline: true,
+            keep_archives: false,
+            arch: "x86_64",
+            os: "linux",
+        };
+        let executor = JavaExecutor::new();
+        let names = |version: &str, context: &ExecutorContext<JavaLanguageContext>| -> Vec<String> {
+            executor
+                .match_installed_versions(version.to_string(), context)
+                .unwrap()
+                .into_iter()
+                .map(|v| v.name)
+                .collect()
+        };
+        assert_eq!(vec!["openjdk-17.0.2", "corretto-17.0.9.8.1", "corretto-17.0.10.7.1"], names("17", &context));
+        assert_eq!(vec!["openjdk-17.0.2"], names("openjdk-17", &context));
+        context.merge(Some(JavaLanguageContext { vendor: Some("Corretto".to_string()), default_vendor: None, no_use: true }));
+        assert_eq!(vec!["corretto-17.0.9.8.1", "corretto-17.0.10.7.1"], names("17", &context));
+        assert_eq!(vec!["corretto-17.0.10.7.1"], names("17.0.10", &context));
+    }
+
+    #[test]
+    fn test_find_release() {
+        let corretto: Vec<_> = ["17.0.9.8.1", "17.0.10.7.1", "21.0.1.12.1"]
+            .iter()
+            .map(|v| JavaVersion {
+                vendor: &CORRETTO,
+                version: v.to_string(),
+                sem_version: parse_semver(&CORRETTO.parse_version(v).unwrap()).unwrap(),
+                packages: vec![],
+            })
+            .collect();
+        let cases = [
+            // (spec, expected)
+            ("17", Some("17.0.10.7.1")),
+            ("corretto-17", Some("17.0.10.7.1")),
+            ("17.0.9", Some("17.0.9.8.1")),
+            ("17.0.9.8.1", Some("17.0.9.8.1")),
+            ("corretto-17.0.9.8.1", Some("17.0.9.8.1")),
+            ("^17.0.9", Some("17.0.10.7.1")),
+            (">=17 <21", Some("17.0.10.7.1")),
+            ("*", Some("21.0.1.12.1")),
+            ("11", None),
+        ];
+        for (spec, expected) in cases {
+            let resolved = find_release(&CORRETTO, spec, &corretto).unwrap().map(|v| v.version.as_str());
+            assert_eq!(expected, resolved, "spec: {}", spec);
+        }
+        assert!(find_release(&CORRETTO, "corretto", &corretto).is_err());
+
+        let openjdk = vec![JavaVersion {
+            vendor: &OPENJDK,
+            version: "21.0.1".to_string(),
+            sem_version: parse_semver("21.0.1").unwrap(),
+            packages: vec![],
+        }];
+        assert_eq!(Some(&openjdk[0]), find_release(&OPENJDK, "openjdk-21", &openjdk).unwrap());
+    }
+}
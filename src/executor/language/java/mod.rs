@@ -2,7 +2,8 @@ mod java_executor;
 mod version;
 mod vendor;
 mod openjdk;
-mod scripts;
 mod corretto;
+mod project;
 
 pub use java_executor::{JavaExecutor, JavaLanguageContext};
+pub use vendor::Vendor;
@@ -1,4 +1,5 @@
 use super::version::{JavaPackage, JavaVersion, PackageKind};
+use crate::tool::cache::IndexCache;
 use crate::tool::http;
 use crate::tool::logger::debug;
 use scraper::{ElementRef, Html, Selector};
@@ -6,22 +7,20 @@ use std::{borrow::BorrowMut, collections::HashMap};
 
 pub type Result<T> = std::result::Result<T, http::HttpError>;
 
-const JAVA_HOME: &str = "https://jdk.java.net/";
-const JAVA_ARCHIVE: &str = "https://jdk.java.net/archive/";
-
 const DOWNLOAD_URL_PREFIX: &str = "https://download.java.net/java";
 
-pub async fn parse_openjdk(proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
+/// release pages are downloaded from `site`, i.e. `https://jdk.java.net/`.
+pub async fn parse_openjdk(cache: &IndexCache, site: &str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
     let mut result = Vec::new();
-    let mut archive_versions = parse_archive(JAVA_ARCHIVE, proxy).await?;
+    let mut archive_versions = parse_archive(cache, &format!("{}archive/", site), proxy).await?;
     result.append(&mut archive_versions);
-    let mut early_access_versions = parse_home(proxy).await?;
+    let mut early_access_versions = parse_home(cache, site, proxy).await?;
     result.append(&mut early_access_versions);
     Ok(result)
 }
 
-async fn parse_home(proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
-    let content = http::download_html(&JAVA_HOME.to_string(), proxy).await?;
+async fn parse_home(cache: &IndexCache, site: &str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
+    let content = cache.download_html(site, proxy).await?;
     let document = Html::parse_document(&content);
     let h1_selector = Selector::parse("h1").unwrap();
     let elements: Vec<_> = document.select(&h1_selector).collect();
@@ -43,7 +42,7 @@ async fn parse_home(proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
                     if href.starts_with('/') {
                         href = format!("https://jdk.java.net{}", href);
                     }
-                    let mut versions = parse_early_access(href.as_str(), proxy).await?;
+                    let mut versions = parse_early_access(cache, &href, proxy).await?;
                     result.append(versions.as_mut());
                 }
             }
@@ -52,8 +51,8 @@ async fn parse_home(proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
     Ok(result)
 }
 
-async fn parse_early_access(url: &str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
-    let content = http::download_html(&url.to_string(), proxy).await?;
+async fn parse_early_access(cache: &IndexCache, url: &str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
+    let content = cache.download_html(url, proxy).await?;
     let document = Html::parse_document(&content);
     let a_selector = Selector::parse("blockquote > table[summary^=\"builds\"] tr a").unwrap();
     let elements: Vec<_> = document.select(&a_selector).collect();
@@ -70,8 +69,8 @@ async fn parse_early_access(url: &str, proxy: &Option<String>) -> Result<Vec<Jav
 }
 
 /// https://download.java.net/java/GA/jdk18.0.1.1/65ae32619e2f40f3a9af3af1851d6e19/2/GPL/openjdk-18.0.1.1_windows-x64_bin.zip
-async fn parse_archive(url: &'static str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
-    let content = http::download_html(&url.to_string(), proxy).await?;
+async fn parse_archive(cache: &IndexCache, url: &str, proxy: &Option<String>) -> Result<Vec<JavaVersion>> {
+    let content = cache.download_html(url, proxy).await?;
     let document = Html::parse_document(&content);
     let a_selector = Selector::parse("#downloads > table[summary^=\"Downloads\"] tr a").unwrap();
     let elements: Vec<_> = document.select(&a_selector).collect();
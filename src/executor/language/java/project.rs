@@ -0,0 +1,76 @@
+use super::super::language_executor::{ProjectVersion, Result};
+use regex::Regex;
+use semver::VersionReq;
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// build files which may declare the Java version, in precedence order.
+static BUILD_FILES: [&str; 3] = ["pom.xml", "build.gradle.kts", "build.gradle"];
+
+/// patterns of the Java version in Maven files, in precedence order.
+static MAVEN_PATTERNS: [&str; 3] = [
+    r"<maven\.compiler\.release>\s*([\d.]+)\s*</maven\.compiler\.release>",
+    r"<java\.version>\s*([\d.]+)\s*</java\.version>",
+    r"<jdk>\s*<version>\s*\[?\s*([\d.]+)",
+];
+
+/// patterns of the Java version in Gradle files, in precedence order.
+static GRADLE_PATTERNS: [&str; 2] = [
+    r#"JavaLanguageVersion\.of\(\s*"?(\d+)"?\s*\)"#,
+    r#"sourceCompatibility\s*=?\s*(?:JavaVersion\.VERSION_)?['"]?(\d+(?:[._]\d+)?)"#,
+];
+
+/// find the nearest Maven or Gradle build file which declares the Java version from `current_dir`,
+/// the version requires the latest release of the major version.
+pub fn find_java_project(current_dir: &Path) -> Result<Option<ProjectVersion>> {
+    for dir in current_dir.ancestors() {
+        for name in BUILD_FILES {
+            let path = dir.join(name);
+            if !path.is_file() {
+                continue;
+            }
+            let patterns: &[&str] = if name == "pom.xml" { &MAVEN_PATTERNS } else { &GRADLE_PATTERNS };
+            if let Some(major) = parse_major_version(&read_to_string(&path)?, patterns) {
+                let req = VersionReq::parse(&format!("^{}", major)).expect("should be a valid version");
+                return Ok(Some(ProjectVersion { path, version: major.to_string(), reqs: vec![req], hint: None }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn parse_major_version(content: &str, patterns: &[&str]) -> Option<u64> {
+    patterns.iter().find_map(|p| {
+        let re = Regex::new(p).unwrap();
+        re.captures(content).and_then(|caps| to_major(&caps[1]))
+    })
+}
+
+/// convert versions like `17`, `1.8` and `1_8` to the major version.
+fn to_major(version: &str) -> Option<u64> {
+    let version = version.replace('_', ".");
+    let version = version.strip_prefix("1.").unwrap_or(&version);
+    version.split('.').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_major_version, GRADLE_PATTERNS, MAVEN_PATTERNS};
+
+    #[test]
+    fn test_parse_major_version() {
+        let pom = r#"<properties>
+            <maven.compiler.release>${java.version}</maven.compiler.release>
+            <java.version>17</java.version>
+        </properties>"#;
+        assert_eq!(Some(17), parse_major_version(pom, &MAVEN_PATTERNS));
+        let toolchains = "<toolchains>\n<jdk>\n<version>[1.8,)</version>\n</jdk>\n</toolchains>";
+        assert_eq!(Some(8), parse_major_version(toolchains, &MAVEN_PATTERNS));
+        assert_eq!(None, parse_major_version("<project></project>", &MAVEN_PATTERNS));
+
+        let kts = "java {\n    toolchain {\n        languageVersion.set(JavaLanguageVersion.of(21))\n    }\n}";
+        assert_eq!(Some(21), parse_major_version(kts, &GRADLE_PATTERNS));
+        assert_eq!(Some(11), parse_major_version("sourceCompatibility = JavaVersion.VERSION_11", &GRADLE_PATTERNS));
+        assert_eq!(Some(8), parse_major_version("sourceCompatibility = '1.8'", &GRADLE_PATTERNS));
+    }
+}
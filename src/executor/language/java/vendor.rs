@@ -2,20 +2,39 @@ use super::version::JavaVersion;
 use super::openjdk::parse_openjdk;
 use super::corretto::parse_corretto;
 use super::super::language_executor::Result;
+use crate::tool::cache::IndexCache;
 
 const NAME_OPENJDK: &str = "openjdk";
 const NAME_CORRETTO: &str = "corretto";
 
-pub static OPENJDK: Vendor = Vendor { name: NAME_OPENJDK };
-pub static CORRETTO: Vendor = Vendor { name: NAME_CORRETTO };
+pub static OPENJDK: Vendor = Vendor {
+    name: NAME_OPENJDK,
+    index_url: "https://jdk.java.net/",
+    download_url: "https://download.java.net/java/",
+};
+pub static CORRETTO: Vendor = Vendor {
+    name: NAME_CORRETTO,
+    index_url: "https://api.github.com/",
+    download_url: "https://corretto.aws/downloads/",
+};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Vendor {
     pub name: &'static str,
+    /// base url of release pages, they are always requested from the release site.
+    pub index_url: &'static str,
+    /// base url of archives, it's replaced when a mirror is configured.
+    pub download_url: &'static str,
 }
 
+static ALL_VENDORS: [&Vendor; 2] = [&OPENJDK, &CORRETTO];
+
 impl Vendor {
-    pub fn from_str(s: &str) -> Option<&Self> {
+    pub fn all() -> &'static [&'static Vendor] {
+        &ALL_VENDORS
+    }
+
+    pub fn from_str(s: &str) -> Option<&'static Self> {
         match s {
             NAME_OPENJDK => Some(&OPENJDK),
             NAME_CORRETTO => Some(&CORRETTO),
@@ -23,22 +42,14 @@ impl Vendor {
         }
     }
 
-    pub fn trim(&self, s: &str) -> String {
-        if s.starts_with(self.name) {
-            s[self.name.len()+1..s.len()].to_string()
-        } else {
-            s.to_string()
-        }
+    /// remove the `vendor-` prefix of `s`.
+    pub fn trim<'s>(&self, s: &'s str) -> &'s str {
+        s.strip_prefix(self.name).and_then(|v| v.strip_prefix('-')).unwrap_or(s)
     }
 
     /// This is used to parse displayed version, as it maybe not a valid semver.
     pub fn parse_version(&self, s: &str) -> Option<String> {
-        // remove the `vendor-` prefix
-        let version = if s.starts_with(self.name) {
-            &s[self.name.len()+1..s.len()]
-        } else {
-            s
-        };
+        let version = self.trim(s);
         match self.name {
             NAME_CORRETTO => {
                 let version_re = regex::Regex::new(r"\d+\.\d+\.\d+(\.(?<build>.*))?").unwrap();
@@ -52,6 +63,8 @@ impl Vendor {
                             None => Some(version.to_string()),
                         }
                     },
+                    // a partial version like `17` is used as a version requirement
+                    None if version.split('.').all(|p| p.parse::<u64>().is_ok()) => Some(version.to_string()),
                     None => None,
                 }
             },
@@ -60,14 +73,18 @@ impl Vendor {
         }
     }
 
-    pub async fn get_versions(&self, proxy: &Option<String>) -> Result<Option<Vec<JavaVersion>>> {
+    pub async fn get_versions(
+        &self,
+        cache: &IndexCache,
+        proxy: &Option<String>,
+    ) -> Result<Option<Vec<JavaVersion>>> {
         match self.name {
             NAME_OPENJDK => {
-                let result = parse_openjdk(proxy).await?;
+                let result = parse_openjdk(cache, self.index_url, proxy).await?;
                 Ok(Some(result))
             },
             NAME_CORRETTO => {
-                let result = parse_corretto().await?;
+                let result = parse_corretto(cache, self.index_url, proxy).await?;
                 Ok(Some(result))
             },
             _ => Ok(None)
@@ -1,15 +1,17 @@
+use super::super::language_executor::archive_platforms;
+use super::super::resolver::ResolvableVersion;
 use super::vendor::{Vendor, CORRETTO, OPENJDK};
 use semver::{BuildMetadata, Prerelease, Version};
 use std::cmp::Ordering;
 
 /// there are three kind of versions:
 ///   * parsed from web page:
-///         * may not follow semver
+///     * may not follow semver
 ///   * used in the program
-///         * no matter where the version comes from, must be converted to a standard semver
+///     * no matter where the version comes from, must be converted to a standard semver
 ///   * displayed to user
-///         * it's better to use the one from web page
-#[derive(Debug, PartialEq, Eq)]
+///     * it's better to use the one from web page
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct JavaVersion {
     pub vendor: &'static Vendor,
     /// version string parsed from web page
@@ -22,10 +24,7 @@ pub struct JavaVersion {
 impl JavaVersion {
     pub fn from_corretto(packages: Vec<JavaPackage>) -> Option<Self> {
         let version = packages[0].version.clone();
-        let version_str = match CORRETTO.parse_version(&version) {
-            Some(v) => v,
-            None => return None,
-        };
+        let version_str = CORRETTO.parse_version(&version)?;
         parse_semver(version_str.as_str()).map(|v| JavaVersion {
             vendor: &CORRETTO,
             version,
@@ -36,10 +35,7 @@ impl JavaVersion {
 
     pub fn from_openjdk(packages: Vec<JavaPackage>) -> Option<Self> {
         let version = packages[0].version.clone();
-        let version_str = match OPENJDK.parse_version(&version) {
-            Some(v) => v,
-            None => return None,
-        };
+        let version_str = OPENJDK.parse_version(&version)?;
         parse_semver(version.as_str()).map(|v| JavaVersion {
             vendor: &OPENJDK,
             version: version_str,
@@ -64,6 +60,11 @@ impl JavaVersion {
         false
     }
 
+    /// platforms which have an archive package, like `linux/x64`.
+    pub fn platforms(&self) -> Vec<String> {
+        archive_platforms(self.packages.iter().map(|p| (p.os.as_str(), p.arch.as_str(), p.kind.is_archive())))
+    }
+
     pub fn get_matched_package(&self, os: &str, arch: &str) -> Option<&JavaPackage> {
         self.packages.iter().find(|package| {
             package.arch.to_lowercase() == arch
@@ -81,6 +82,16 @@ impl JavaVersion {
     }
 }
 
+impl ResolvableVersion for JavaVersion {
+    fn is_named(&self, spec: &str) -> bool {
+        self.version == spec
+    }
+
+    fn sem_version(&self) -> &Version {
+        &self.sem_version
+    }
+}
+
 impl Ord for JavaVersion {
     fn cmp(&self, other: &Self) -> Ordering {
         self.sem_version.cmp(&other.sem_version)
@@ -93,7 +104,7 @@ impl PartialOrd<JavaVersion> for JavaVersion {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct JavaPackage {
     pub version: String,
     pub url: String,
@@ -105,7 +116,7 @@ pub struct JavaPackage {
     pub checksum_sha256: Option<String>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PackageKind {
     Tar,
     Pkg,
@@ -146,7 +157,7 @@ impl PackageKind {
     }
 }
 
-fn parse_semver(version: &str) -> Option<Version> {
+pub fn parse_semver(version: &str) -> Option<Version> {
     let version_re =
         regex::Regex::new(r"(\d+)(\.(\d+))?(\.(\d+))?(-([\w.]+))?(\+([\w.]+))?").unwrap();
     if let Some(caps) = version_re.captures(version) {
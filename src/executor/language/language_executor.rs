@@ -1,11 +1,16 @@
 use crate::tool::fs::{make_link, remove_link, FSError, LanguageDir};
+use crate::tool::cache::IndexCache;
 use crate::tool::http::HttpError;
 use crate::tool::logger::debug;
-use semver::Version;
-use std::collections::HashMap;
+use super::version_file::{find_version_file, version_env_name, write_version_file, VersionFile};
+use super::resolver::{InstalledVersion, VersionResolver};
+use super::script::generate_scripts;
+use crate::tool::shim::{find_executable, get_executable_names, write_shims};
+use semver::{Version, VersionReq};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Write;
 use std::fs::{create_dir, read_dir, read_link, remove_dir_all};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, LanguageError>;
@@ -13,14 +18,24 @@ pub type Result<T> = std::result::Result<T, LanguageError>;
 /// LanguageExecutor uses to execute commands for language version management.
 pub trait LanguageExecutor<'a, T: LanguageContext> {
     fn name(&self) -> &str;
-    fn get_env_hint(&self, current_dir: &Path) -> String;
     fn list(
         &self,
         local_only: bool,
         context: &'a ExecutorContext<'a, T>,
     ) -> Result<Vec<LanguageVersion>>;
     fn install(&self, version: String, context: &'a ExecutorContext<'a, T>) -> Result<InstallResult>;
-    fn post_venv(&self, dir: &Path) -> Result<()>;
+    /// get names of stable releases which can be installed on current OS and arch,
+    /// they are named as installed versions, e.g. `corretto-21.0.1.12.1`.
+    fn list_releases(&self, context: &ExecutorContext<'a, T>) -> Result<Vec<String>>;
+    /// get details of the release matched by `version` from the version index.
+    fn info(&self, version: String, context: &ExecutorContext<'a, T>) -> Result<ReleaseInfo>;
+
+    /// generate scripts to activate the virtual environment in `dir` from `get_env`.
+    fn post_venv(&self, dir: &Path) -> Result<()> {
+        let env = self.get_env(dir, &dir.join(self.name()));
+        generate_scripts(dir, self.name(), &env)?;
+        Ok(())
+    }
 
     fn unuse(&self, context: &'a ExecutorContext<'a, T>) -> Result<()> {
         let current_version = self.get_current_version(context);
@@ -31,46 +46,476 @@ pub trait LanguageExecutor<'a, T: LanguageContext> {
         Ok(())
     }
 
-    fn select(&self, version: String, context: &'a ExecutorContext<'a, T>) -> Result<UseResult> {
-        let installed_version = self.get_installed_versions(context)?;
-        let mut show_hint = false;
-        if installed_version.contains_key(&version) {
-            let current_version = self.get_current_version(context);
-            let current_dir = context.language_dir.get_current_dir();
-            let versions_dir = context.language_dir.get_versions_dir();
-            let version_dir = versions_dir.join(&version);
-            if current_version.is_none() {
-                show_hint = true;
-                make_link(current_dir, &version_dir)?;
-            } else if current_version.is_some_and(|v| v != version) {
-                remove_link(current_dir)?;
-                make_link(current_dir, &version_dir)?;
-            } else {
-                return Ok(UseResult::VersionAlreadyUsed);
+    /// list installed versions which match `context.filter`, sorted by version,
+    /// release channels are only known by remote indexes, so `lts` and `stable` are rejected.
+    fn list_installed(&self, context: &ExecutorContext<'a, T>) -> Result<Vec<LanguageVersion>> {
+        let filter = &context.filter;
+        if filter.lts || filter.stable.is_some() {
+            return Err(LanguageError::General("`--lts`, `--stable` and `--unstable` can't be used with `--local`"));
+        }
+        let text_re = filter.text_regex();
+        let mut versions: Vec<_> = self
+            .get_installed_versions(context)?
+            .into_iter()
+            .filter_map(|(name, inuse)| {
+                let version = self.parse_installed_version(&name);
+                let matched = text_re.as_ref().is_none_or(|re| re.is_match(&name))
+                    && (filter.major.is_none() || version.as_ref().is_some_and(|v| filter.matches_major(v)));
+                // prefix of the version, e.g. vendor of Java
+                let prefix = name.strip_suffix(self.get_version_of_installed(&name)).unwrap_or("").to_string();
+                matched.then_some((prefix, version, name, inuse))
+            })
+            .collect();
+        versions.sort();
+        let result = filter
+            .keep_latest_per_minor(versions, |(prefix, v, _, _)| v.as_ref().map(|v| (prefix.clone(), v.major, v.minor)))
+            .into_iter()
+            .map(|(_, _, name, inuse)| LanguageVersion {
+                version: name,
+                installed: true,
+                inuse,
+            })
+            .collect();
+        Ok(result)
+    }
+
+    /// convert `version` given by user to the name of an installed version,
+    /// the name is returned as it is if no installed version matches.
+    fn resolve_installed_version(
+        &self,
+        version: String,
+        _context: &ExecutorContext<'a, T>,
+    ) -> Result<String> {
+        Ok(version)
+    }
+
+    /// get version part of an installed version name, e.g. `21.0.1` of `corretto-21.0.1`.
+    fn get_version_of_installed<'b>(&self, name: &'b str) -> &'b str {
+        name
+    }
+
+    /// parse the version of an installed version name, used to match version requirements.
+    fn parse_installed_version(&self, name: &str) -> Option<Version> {
+        Version::parse(self.get_version_of_installed(name)).ok()
+    }
+
+    /// parse an installed version name, `None` if its version cannot be parsed.
+    fn parse_installed(&self, name: String) -> Option<InstalledVersion> {
+        let sem_version = self.parse_installed_version(&name)?;
+        let version = self.get_version_of_installed(&name).to_string();
+        Some(InstalledVersion {
+            name,
+            version,
+            sem_version,
+        })
+    }
+
+    /// installed versions whose version can be parsed.
+    fn get_installed_list(&self, context: &ExecutorContext<'a, T>) -> Result<Vec<InstalledVersion>> {
+        let result = self
+            .get_installed_versions(context)?
+            .into_keys()
+            .filter_map(|name| self.parse_installed(name))
+            .collect();
+        Ok(result)
+    }
+
+    /// get release line of a version, newer patch releases of the same line are upgrades of it,
+    /// it's major and minor version by default.
+    fn release_line(&self, version: &Version) -> (u64, u64) {
+        (version.major, version.minor)
+    }
+
+    /// get the newest stable release in `releases` which is newer than `installed` in the same release line.
+    fn find_upgrade<'b>(&self, installed: &InstalledVersion, releases: &'b [InstalledVersion]) -> Option<&'b InstalledVersion> {
+        // prefix of the version, e.g. vendor of Java
+        let prefix = installed.name.strip_suffix(&installed.version).unwrap_or("");
+        let line = self.release_line(&installed.sem_version);
+        releases
+            .iter()
+            .filter(|r| {
+                r.name.starts_with(prefix)
+                    && r.sem_version.pre.is_empty()
+                    && self.release_line(&r.sem_version) == line
+                    && r.sem_version > installed.sem_version
+            })
+            .max_by(|a, b| a.sem_version.cmp(&b.sem_version))
+    }
+
+    /// get installed versions which have a newer release in the same release line, sorted by version.
+    fn outdated(&self, context: &ExecutorContext<'a, T>) -> Result<Vec<OutdatedVersion>> {
+        let mut installed = self.get_installed_list(context)?;
+        if installed.is_empty() {
+            return Ok(vec![]);
+        }
+        installed.sort_by(|a, b| a.sem_version.cmp(&b.sem_version).then_with(|| a.name.cmp(&b.name)));
+        let releases: Vec<_> = self
+            .list_releases(context)?
+            .into_iter()
+            .filter_map(|name| self.parse_installed(name))
+            .collect();
+        let current_version = self.get_current_version(context);
+        let result = installed
+            .into_iter()
+            .filter_map(|v| {
+                let latest = self.find_upgrade(&v, &releases)?.name.clone();
+                Some(OutdatedVersion {
+                    inuse: current_version.as_ref().is_some_and(|c| *c == v.name),
+                    installed: v.name,
+                    latest,
+                })
+            })
+            .collect();
+        Ok(result)
+    }
+
+    /// install the newest release in the release line of the installed version matched by `version`,
+    /// or of the globally used version if `version` is not given,
+    /// then move `current` and virtual environments linked to the old version to the new one,
+    /// the old version is uninstalled if `prune` is set.
+    fn upgrade(&self, version: Option<String>, prune: bool, context: &'a ExecutorContext<'a, T>) -> Result<UpgradeResult> {
+        let name = match version {
+            Some(v) => self.resolve_installed(v, context)?,
+            None => match self.get_current_version(context) {
+                Some(v) => v,
+                None => return Err(LanguageError::NoUsedVersion(self.name().to_string())),
+            },
+        };
+        let installed = match self.parse_installed(name.clone()) {
+            Some(v) => v,
+            None => return Err(LanguageError::GeneralString(format!("cannot parse the installed version({})", name))),
+        };
+        let releases: Vec<_> = self
+            .list_releases(context)?
+            .into_iter()
+            .filter_map(|name| self.parse_installed(name))
+            .collect();
+        let latest = match self.find_upgrade(&installed, &releases) {
+            Some(v) => v.name.clone(),
+            None => return Ok(UpgradeResult::UpToDate(name)),
+        };
+        debug!("upgrade {} to {}", name, latest);
+        match self.install(latest.clone(), context) {
+            Ok(_) | Err(LanguageError::VersionInstalled()) => {}
+            Err(e) => return Err(e),
+        }
+        if self.get_current_version(context).is_some_and(|v| v == name) {
+            self.select(latest.clone(), context)?;
+        }
+        let latest_dir = context.language_dir.get_versions_dir().join(&latest);
+        for venv in context.language_dir.get_venvs()? {
+            if self.get_linked_name(&venv).is_some_and(|v| v == name) {
+                debug!("re-point venv {:?} to {}", venv, latest);
+                remove_link(&venv)?;
+                self.link_dir(&venv, &latest_dir)?;
             }
+        }
+        if prune {
+            self.uninstall(name.clone(), context)?;
+        }
+        Ok(UpgradeResult::Upgraded(name, latest))
+    }
+
+    /// get installed versions which match `version`, sorted by version,
+    /// only the installed version is returned if `version` is its exact name.
+    fn match_installed_versions(
+        &self,
+        version: String,
+        context: &ExecutorContext<'a, T>,
+    ) -> Result<Vec<InstalledVersion>> {
+        let name = self.resolve_installed_version(version, context)?;
+        let mut installed = self.get_installed_list(context)?;
+        if let Some(v) = installed.iter().find(|v| v.name == name) {
+            return Ok(vec![v.clone()]);
+        }
+        let spec = self.get_version_of_installed(&name);
+        // prefix of the version, e.g. vendor of Java
+        let prefix = name.strip_suffix(spec).unwrap_or("");
+        let resolver = match VersionResolver::parse(spec, |s| self.parse_installed_version(s)) {
+            Some(r) => r,
+            None => return Ok(vec![]),
+        };
+        installed.retain(|v| v.name.starts_with(prefix) && resolver.matches(&v.sem_version));
+        installed.sort_by(|a, b| a.sem_version.cmp(&b.sem_version).then_with(|| a.name.cmp(&b.name)));
+        Ok(installed)
+    }
+
+    /// match `version` given by user or a version file with installed versions,
+    /// a partial version like `20` or `1.21` or a range like `^1.20` matches the latest installed version of it.
+    fn match_installed_version(
+        &self,
+        version: String,
+        context: &ExecutorContext<'a, T>,
+    ) -> Result<Option<String>> {
+        let mut candidates = self.match_installed_versions(version.clone(), context)?;
+        if let [.., a, b] = candidates.as_slice() {
+            if a.sem_version == b.sem_version {
+                let names: Vec<_> = candidates.iter().map(|v| v.name.as_str()).collect();
+                return Err(LanguageError::AmbiguousVersion(version, names.join(", ")));
+            }
+        }
+        Ok(candidates.pop().map(|v| v.name))
+    }
+
+    /// get the installed version matched by `version`, or an error if there is none.
+    fn resolve_installed(&self, version: String, context: &ExecutorContext<'a, T>) -> Result<String> {
+        match self.match_installed_version(version.clone(), context)? {
+            Some(v) => Ok(v),
+            None => Err(LanguageError::VersionNotInstalled(version)),
+        }
+    }
+
+    /// get version required by the project in `current_dir`, from files like `go.mod`.
+    fn get_project_version(&self, _current_dir: &Path) -> Result<Option<ProjectVersion>> {
+        Ok(None)
+    }
+
+    /// get the latest installed version which matches `project`.
+    fn match_installed_req(
+        &self,
+        project: &ProjectVersion,
+        context: &ExecutorContext<'a, T>,
+    ) -> Result<Option<String>> {
+        let installed_versions = self.get_installed_versions(context)?;
+        let result = installed_versions
+            .into_keys()
+            .filter_map(|n| {
+                let v = self.parse_installed_version(&n)?;
+                project.matches(&v).then_some((v, n))
+            })
+            .max()
+            .map(|(_, n)| n);
+        Ok(result)
+    }
+
+    /// get version required by the project in `current_dir`,
+    /// with `installed_only`, the version is the latest matched installed version.
+    fn resolve_project_version(
+        &self,
+        installed_only: bool,
+        current_dir: &Path,
+        context: &ExecutorContext<'a, T>,
+    ) -> Result<(ProjectVersion, String)> {
+        let project = match self.get_project_version(current_dir)? {
+            Some(p) => p,
+            None => return Err(LanguageError::NoProjectVersion(self.name().to_string())),
+        };
+        if !installed_only {
+            let version = project.version.clone();
+            return Ok((project, version));
+        }
+        match self.match_installed_req(&project, context)? {
+            Some(v) => Ok((project, v)),
+            None => Err(LanguageError::VersionNotInstalled(project.version)),
+        }
+    }
+
+    /// get the project version which the installed `version` doesn't satisfy.
+    fn check_project_version(&self, version: &str, current_dir: &Path) -> Result<Option<ProjectVersion>> {
+        let project = self.get_project_version(current_dir)?;
+        let result = project.filter(|p| {
+            self.parse_installed_version(version).is_some_and(|v| !p.matches(&v))
+        });
+        Ok(result)
+    }
+
+    /// get version pinned by environment variable `UVM_<LANG>_VERSION`, or the nearest version file
+    /// from `current_dir`, or the global version if there is neither of them.
+    fn current(&self, current_dir: &Path, context: &ExecutorContext<'a, T>) -> Result<CurrentResult> {
+        let env_name = version_env_name(self.name());
+        if let Some(version) = std::env::var(&env_name).ok().filter(|v| !v.is_empty()) {
+            let installed = self.match_installed_version(version.clone(), context)?;
+            return Ok(CurrentResult::Env(env_name, version, installed));
+        }
+        match find_version_file(self.name(), current_dir)? {
+            Some(file) => {
+                let installed = self.match_installed_version(file.version.clone(), context)?;
+                Ok(CurrentResult::Local(file, installed))
+            }
+            None => Ok(CurrentResult::Global(self.get_current_version(context))),
+        }
+    }
+
+    /// get directory of executables of an installed version.
+    fn get_bin_dir(&self, version_dir: &Path) -> PathBuf {
+        version_dir.join("bin")
+    }
+
+    /// get environment variables required by an installed version,
+    /// `home_dir` is the language home, or the folder of a virtual environment.
+    fn get_env(&self, _home_dir: &Path, version_dir: &Path) -> LanguageEnv {
+        LanguageEnv {
+            paths: vec![self.get_bin_dir(version_dir)],
+            vars: vec![],
+        }
+    }
+
+    /// get the hint to set environment variables for the globally used version.
+    fn get_env_hint(&self, home_dir: &Path) -> String {
+        let env = self.get_env(home_dir, &home_dir.join("current"));
+        let paths: Vec<_> = env.paths.iter().map(|p| p.to_string_lossy()).collect();
+        if cfg!(target_os = "windows") {
+            let mut hint = format!("please add `{}` to your path", paths.join(";"));
+            for (name, value) in &env.vars {
+                let _ = write!(hint, " and set {} to `{}`", name, value.to_string_lossy());
+            }
+            hint
         } else {
-            return Err(LanguageError::VersionNotInstalled(version));
+            let mut hint = format!(
+                "please add `{0}` to your path.\nfor example:\n    export PATH=\"{0}:$PATH\"",
+                paths.join(":")
+            );
+            for (name, value) in &env.vars {
+                let _ = write!(hint, "\n    export {}=\"{}\"", name, value.to_string_lossy());
+            }
+            hint
+        }
+    }
+
+    /// get the installed version matching `version`, and environment variables required by it.
+    fn get_version_env(&self, version: &str, context: &ExecutorContext<'a, T>) -> Result<(String, LanguageEnv)> {
+        match self.match_installed_version(version.to_string(), context)? {
+            Some(v) => {
+                let env = self.get_env(
+                    context.language_dir.get_home_dir(),
+                    &context.language_dir.get_versions_dir().join(&v),
+                );
+                Ok((v, env))
+            }
+            None => Err(LanguageError::VersionNotInstalled(version.to_string())),
         }
-        if show_hint {
+    }
+
+    /// get names of executables of all installed versions.
+    fn get_executables(&self, context: &ExecutorContext<'a, T>) -> Result<BTreeSet<String>> {
+        let versions_dir = context.language_dir.get_versions_dir();
+        let mut result = BTreeSet::new();
+        for version in self.get_installed_versions(context)?.into_keys() {
+            result.extend(get_executable_names(&self.get_bin_dir(&versions_dir.join(version)))?);
+        }
+        Ok(result)
+    }
+
+    /// regenerate shims for executables of all installed versions.
+    fn reshim(&self, context: &ExecutorContext<'a, T>) -> Result<()> {
+        let names = self.get_executables(context)?;
+        let uvm_exe = std::env::current_exe()?;
+        debug!("write shims of {}: {:?}", self.name(), &names);
+        write_shims(context.shims_dir, &uvm_exe, self.name(), &names)?;
+        Ok(())
+    }
+
+    /// get the installed version used in `current_dir`.
+    fn get_used_version(&self, current_dir: &Path, context: &ExecutorContext<'a, T>) -> Result<String> {
+        match self.current(current_dir, context)? {
+            CurrentResult::Env(_, _, Some(v)) | CurrentResult::Local(_, Some(v)) | CurrentResult::Global(Some(v)) => {
+                Ok(v)
+            }
+            CurrentResult::Env(_, v, None) | CurrentResult::Local(VersionFile { version: v, .. }, None) => {
+                Err(LanguageError::VersionNotInstalled(v))
+            }
+            CurrentResult::Global(None) => Err(LanguageError::NoUsedVersion(self.name().to_string())),
+        }
+    }
+
+    /// get environment variables required by the version used in `current_dir`,
+    /// `None` if no version is used.
+    fn get_used_env(&self, current_dir: &Path, context: &ExecutorContext<'a, T>) -> Result<Option<LanguageEnv>> {
+        match self.get_used_version(current_dir, context) {
+            Ok(version) => {
+                let version_dir = context.language_dir.get_versions_dir().join(version);
+                Ok(Some(self.get_env(context.language_dir.get_home_dir(), &version_dir)))
+            }
+            Err(LanguageError::NoUsedVersion(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// get executable `name` of the version used in `current_dir`, and environment variables required by it.
+    fn get_shim_target(
+        &self,
+        name: &str,
+        current_dir: &Path,
+        context: &ExecutorContext<'a, T>,
+    ) -> Result<(PathBuf, LanguageEnv)> {
+        let version = self.get_used_version(current_dir, context)?;
+        let version_dir = context.language_dir.get_versions_dir().join(&version);
+        match find_executable(&self.get_bin_dir(&version_dir), name) {
+            Some(bin) => Ok((bin, self.get_env(context.language_dir.get_home_dir(), &version_dir))),
+            None => Err(LanguageError::NoExecutable(name.to_string(), version)),
+        }
+    }
+
+    /// get path of executable `name` of the version used in `current_dir`,
+    /// `None` if no installed version of the language provides it.
+    fn which(&self, name: &str, current_dir: &Path, context: &ExecutorContext<'a, T>) -> Result<Option<PathBuf>> {
+        if !self.get_executables(context)?.contains(name) {
+            return Ok(None);
+        }
+        let (bin, _) = self.get_shim_target(name, current_dir, context)?;
+        Ok(Some(bin))
+    }
+
+    /// get the install root of the installed version matching `version`,
+    /// or of the version used in `current_dir` if `version` is not given.
+    fn home(
+        &self,
+        version: Option<String>,
+        current_dir: &Path,
+        context: &ExecutorContext<'a, T>,
+    ) -> Result<(String, PathBuf)> {
+        let version = match version {
+            Some(v) => self.resolve_installed(v, context)?,
+            None => self.get_used_version(current_dir, context)?,
+        };
+        let version_dir = context.language_dir.get_versions_dir().join(&version);
+        Ok((version, version_dir))
+    }
+
+    /// pin `version` for the project in `current_dir` by writing a version file.
+    fn local(&self, version: String, current_dir: &Path, context: &'a ExecutorContext<'a, T>) -> Result<PathBuf> {
+        let version = self.resolve_installed_version(version, context)?;
+        Ok(write_version_file(self.name(), current_dir, &version)?)
+    }
+
+    fn select(&self, version: String, context: &'a ExecutorContext<'a, T>) -> Result<UseResult> {
+        let version = self.resolve_installed(version, context)?;
+        let current_version = self.get_current_version(context);
+        let current_dir = context.language_dir.get_current_dir();
+        let versions_dir = context.language_dir.get_versions_dir();
+        let version_dir = versions_dir.join(&version);
+        if current_version.is_none() {
+            make_link(current_dir, &version_dir)?;
             Ok(UseResult::SuccessNeedHint)
-        } else {
+        } else if current_version.is_some_and(|v| v != version) {
+            remove_link(current_dir)?;
+            make_link(current_dir, &version_dir)?;
             Ok(UseResult::Success)
+        } else {
+            Ok(UseResult::VersionAlreadyUsed)
         }
     }
 
+    /// uninstall the installed version matched by `version`, it must match only one version.
     fn uninstall(&self, version: String, context: &'a ExecutorContext<'a, T>) -> Result<()> {
+        let matched = self.match_installed_versions(version.clone(), context)?;
+        let version = match matched.as_slice() {
+            [] => return Err(LanguageError::VersionNotInstalled(version)),
+            [v] => v.name.clone(),
+            _ => {
+                let names: Vec<_> = matched.iter().map(|v| v.name.as_str()).collect();
+                return Err(LanguageError::AmbiguousVersion(version, names.join(", ")));
+            }
+        };
         let current_version = self.get_current_version(context);
         if current_version.is_some_and(|v| v == version) {
             let current_dir = context.language_dir.get_current_dir();
             remove_link(current_dir)?;
         }
 
-        let installed_version = self.get_installed_versions(context)?;
-        if installed_version.contains_key(&version) {
-            let versions = context.language_dir.get_versions_dir();
-            let version_dir = versions.join(&version);
-            remove_dir_all(version_dir)?;
-        }
+        let versions = context.language_dir.get_versions_dir();
+        let version_dir = versions.join(&version);
+        remove_dir_all(version_dir)?;
         Ok(())
     }
 
@@ -80,14 +525,10 @@ pub trait LanguageExecutor<'a, T: LanguageContext> {
         dir_name: String,
         context: &'a ExecutorContext<'a, T>,
     ) -> Result<VenvResult> {
+        let version = self.resolve_installed(version, context)?;
         let current_dir = std::env::current_dir()?;
         debug!("current dir is {:?}, version: {}", &current_dir, &version);
         let venv_dir = current_dir.join(dir_name);
-        let installed_version = self.get_installed_versions(context)?;
-        debug!("installed versions: {:?}", installed_version);
-        if !installed_version.contains_key(&version) {
-            return Err(LanguageError::VersionNotInstalled(version));
-        }
         let need_create = if venv_dir.exists() && venv_dir.is_dir() {
             let link_dir = venv_dir.join(self.name());
             let linked_name = self.get_linked_name(&link_dir);
@@ -107,12 +548,19 @@ pub trait LanguageExecutor<'a, T: LanguageContext> {
             let link_dir = venv_dir.join(self.name());
             let versions_dir = context.language_dir.get_versions_dir();
             let version_dir = versions_dir.join(&version);
-            make_link(&link_dir, &version_dir)?;
+            self.link_dir(&link_dir, &version_dir)?;
+            context.language_dir.add_venv(&link_dir)?;
             self.post_venv(&venv_dir)?;
         }
         Ok(VenvResult::Success)
     }
 
+    /// get install root of the installed version `name`, `None` if it's not installed.
+    fn get_installed_dir(&self, name: &str, context: &ExecutorContext<'a, T>) -> Result<Option<PathBuf>> {
+        let installed = self.get_installed_versions(context)?.contains_key(name);
+        Ok(installed.then(|| context.language_dir.get_versions_dir().join(name)))
+    }
+
     /// get all installed version locally.
     fn get_installed_versions(
         &self,
@@ -174,6 +622,45 @@ pub enum InstallResult {
     VersionInstalled,
 }
 
+/// OutdatedVersion is an installed version which has a newer release in the same release line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutdatedVersion {
+    pub installed: String,
+    /// the newest release in the release line.
+    pub latest: String,
+    pub inuse: bool,
+}
+
+#[derive(Clone, Debug)]
+pub enum UpgradeResult {
+    /// there is no newer release of the version.
+    UpToDate(String),
+    /// the old version, and the newly installed version.
+    Upgraded(String, String),
+}
+
+/// ReleaseInfo is details of a release carried by the version index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleaseInfo {
+    /// name of the release, named as installed versions.
+    pub name: String,
+    /// details like release date, shown as `key: value`.
+    pub details: Vec<(&'static str, String)>,
+    pub packages: Vec<PackageInfo>,
+    /// install root of the release, `None` if it's not installed.
+    pub installed_dir: Option<PathBuf>,
+}
+
+/// PackageInfo is a downloadable file of a release.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageInfo {
+    /// os and arch, like `linux/x64`.
+    pub platform: String,
+    pub kind: String,
+    /// other details like file name, size and checksum.
+    pub details: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub enum UseResult {
     Success,
@@ -181,6 +668,62 @@ pub enum UseResult {
     VersionAlreadyUsed,
 }
 
+#[derive(Clone, Debug)]
+pub enum CurrentResult {
+    /// environment variable, its version, and the installed version it matches.
+    Env(String, String, Option<String>),
+    /// version file, and the installed version it matches.
+    Local(VersionFile, Option<String>),
+    /// no version file is found, the globally used version.
+    Global(Option<String>),
+}
+
+/// LanguageEnv is environment variables required by a version.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LanguageEnv {
+    /// directories prepended to `PATH`.
+    pub paths: Vec<PathBuf>,
+    /// other variables, e.g. `GOROOT`, `JAVA_HOME`.
+    pub vars: Vec<(&'static str, PathBuf)>,
+}
+
+impl LanguageEnv {
+    /// merge `other`, which has lower precedence.
+    pub fn merge(&mut self, other: LanguageEnv) {
+        self.paths.extend(other.paths);
+        for (name, value) in other.vars {
+            if !self.vars.iter().any(|(n, _)| *n == name) {
+                self.vars.push((name, value));
+            }
+        }
+    }
+}
+
+/// ProjectVersion is the version required by a project file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectVersion {
+    pub path: PathBuf,
+    /// version passed to `install` command, which may be narrower than `reqs`,
+    /// e.g. the latest patch release of `go 1.21` is installed while any newer version satisfies it.
+    pub version: String,
+    /// requirements used to match installed versions, a version matches if it matches any of them.
+    pub reqs: Vec<VersionReq>,
+    /// extra requirement of the project shown to user, e.g. `packageManager` of `package.json`.
+    pub hint: Option<String>,
+}
+
+impl ProjectVersion {
+    pub fn matches(&self, version: &Version) -> bool {
+        self.reqs.iter().any(|r| r.matches(version))
+    }
+
+    /// requirements shown to user, e.g. `>=1.21.0`.
+    pub fn requirement(&self) -> String {
+        let reqs: Vec<_> = self.reqs.iter().map(|r| r.to_string()).collect();
+        reqs.join(" || ")
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum VenvResult {
     Success,
@@ -191,9 +734,22 @@ pub enum VenvResult {
 #[derive(Clone, Debug)]
 pub struct ExecutorContext<'a, T: LanguageContext> {
     pub proxy: Option<String>,
+    /// base url of a mirror site, which replaces the official download site.
+    pub mirror: Option<String>,
     pub language_dir: &'a LanguageDir,
+    /// directory of shims, which are regenerated after installing or uninstalling.
+    pub shims_dir: &'a Path,
     pub language_context: Option<T>,
-    pub filter: Option<&'a String>,
+    /// used in `list` command.
+    pub filter: ListFilter,
+    /// user-defined version aliases from the configuration.
+    pub aliases: BTreeMap<String, String>,
+    /// cache of remote version indexes.
+    pub index_cache: IndexCache,
+    /// use cached indexes and downloaded archives only.
+    pub offline: bool,
+    /// keep downloaded archives after installing, so they can be installed again in offline mode.
+    pub keep_archives: bool,
     pub arch: &'a str,
     pub os: &'a str,
 }
@@ -203,13 +759,84 @@ impl<'a, T: LanguageContext> ExecutorContext<'a, T> {
         self.language_context = language_context;
         self
     }
+
+    /// in offline mode, nothing is downloaded.
+    pub fn set_offline(&mut self, offline: bool) -> &mut Self {
+        self.offline = offline;
+        self.index_cache.set_offline(offline);
+        self
+    }
+
+    /// replace a user-defined alias with its version, other versions are returned as they are.
+    pub fn resolve_alias(&self, version: String) -> String {
+        match self.aliases.get(&version) {
+            Some(v) => {
+                debug!("resolve alias {} to {}", version, v);
+                v.clone()
+            }
+            None => version,
+        }
+    }
+}
+
+/// ListFilter filters versions shown by `list` command.
+#[derive(Clone, Debug, Default)]
+pub struct ListFilter {
+    /// text in version names, or in file names for go, case insensitive.
+    pub text: Option<String>,
+    pub major: Option<u64>,
+    /// keep LTS releases only, node only.
+    pub lts: bool,
+    /// keep stable releases if it's true, or unstable releases if it's false, go only.
+    pub stable: Option<bool>,
+    /// keep the newest patch release of each minor version.
+    pub latest_per_minor: bool,
+    /// keep versions which have a package for current OS and arch.
+    pub installable: bool,
+}
+
+impl ListFilter {
+    pub fn text_regex(&self) -> Option<regex::Regex> {
+        self.text.as_ref().filter(|t| !t.is_empty()).map(|t| {
+            regex::RegexBuilder::new(&regex::escape(t))
+                .case_insensitive(true)
+                .build()
+                .expect("escaped text should be a valid regex")
+        })
+    }
+
+    pub fn matches_major(&self, version: &Version) -> bool {
+        self.major.is_none_or(|m| m == version.major)
+    }
+
+    /// keep the last item of each release line if `latest_per_minor` is set,
+    /// `line` gets the release line of an item, e.g. major and minor version,
+    /// `items` should be sorted in ascending order.
+    pub fn keep_latest_per_minor<V, K: PartialEq, F: Fn(&V) -> Option<K>>(&self, items: Vec<V>, line: F) -> Vec<V> {
+        if !self.latest_per_minor {
+            return items;
+        }
+        let mut result: Vec<V> = Vec::new();
+        for item in items {
+            let key = line(&item);
+            if key.is_some() && result.last().is_some_and(|last| line(last) == key) {
+                result.pop();
+            }
+            result.push(item);
+        }
+        result
+    }
 }
 
 pub trait LanguageContext {}
 
 pub struct GeneralLanguageContext {
     /// used in `install` command, whether to use installed version instantly.
-    pub no_use: bool
+    pub no_use: bool,
+    /// used in `install` command, whether to prefer LTS releases, node only.
+    pub lts: bool,
+    /// used in `install` command, whether pre-releases match the version.
+    pub prerelease: bool,
 }
 
 impl LanguageContext for GeneralLanguageContext {}
@@ -247,16 +874,26 @@ pub enum LanguageError {
     FS(#[from] FSError),
     #[error("no matched version with {0}")]
     NoSuchVersion(String),
-    #[error("no matched arch({0}) or os({1})")]
-    NoMatchedArchOrOS(String, String),
+    #[error("no matched arch({0}) or os({1}), supported platforms: {2}")]
+    NoMatchedArchOrOS(String, String, String),
     #[error("{0}")]
-    Octocrab(#[from] octocrab::Error),
+    Json(#[from] serde_json::Error),
     #[error("failed to read local fs")]
     FailedToReadFS(),
     #[error("Version already installed")]
     VersionInstalled(),
+    #[error("No project file which requires a {0} version is found")]
+    NoProjectVersion(String),
+    #[error("No {0} version is used, please use a version by `use` command or a version file")]
+    NoUsedVersion(String),
+    #[error("{0} is not found in version {1}")]
+    NoExecutable(String, String),
     #[error("Version {0} has not been installed")]
     VersionNotInstalled(String),
+    #[error("Version {0} matches multiple installed versions: {1}, please specify one of them")]
+    AmbiguousVersion(String, String),
+    #[error("{0} has not been downloaded, it cannot be downloaded in offline mode")]
+    Offline(String),
     #[error("Failed to parse versions")]
     Html(),
     #[error("Failed to verify the file")]
@@ -266,3 +903,47 @@ pub enum LanguageError {
     #[error("{0}")]
     GeneralString(String),
 }
+
+/// platforms which have an archive package, like `linux/x64`, from `(os, arch, is_archive)` of packages.
+pub fn archive_platforms<'p>(packages: impl Iterator<Item = (&'p str, &'p str, bool)>) -> Vec<String> {
+    let mut result: Vec<_> = packages
+        .filter(|(_, _, is_archive)| *is_archive)
+        .map(|(os, arch, _)| format!("{}/{}", os.to_lowercase(), arch.to_lowercase()))
+        .collect();
+    result.sort();
+    result.dedup();
+    result
+}
+
+impl LanguageError {
+    /// `NoMatchedArchOrOS` with platforms supported by the version.
+    pub fn no_matched_platform(arch: &str, os: &str, platforms: Vec<String>) -> Self {
+        let platforms = if platforms.is_empty() { "none".to_string() } else { platforms.join(", ") };
+        LanguageError::NoMatchedArchOrOS(arch.to_string(), os.to_string(), platforms)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{archive_platforms, ListFilter};
+    use semver::Version;
+
+    #[test]
+    fn test_keep_latest_per_minor() {
+        let versions: Vec<_> = ["1.20.1", "1.21.0", "1.21.6", "1.22.0"]
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .collect();
+        let filter = ListFilter { latest_per_minor: true, ..ListFilter::default() };
+        let result = filter.keep_latest_per_minor(versions.clone(), |v| Some((v.major, v.minor)));
+        assert_eq!(vec![versions[0].clone(), versions[2].clone(), versions[3].clone()], result);
+        assert_eq!(4, ListFilter::default().keep_latest_per_minor(versions, |v| Some((v.major, v.minor))).len());
+        assert!(!ListFilter { major: Some(2), ..ListFilter::default() }.matches_major(&Version::new(1, 21, 0)));
+    }
+
+    #[test]
+    fn test_archive_platforms() {
+        let packages = [("Linux", "x64", true), ("darwin", "arm64", true), ("linux", "x64", true), ("windows", "x64", false)];
+        assert_eq!(vec!["darwin/arm64", "linux/x64"], archive_platforms(packages.into_iter()));
+    }
+}
@@ -1,4 +1,7 @@
 mod language_executor;
+mod version_file;
+mod resolver;
+mod script;
 mod node;
 mod golang;
 mod java;
@@ -10,8 +13,15 @@ pub use language_executor::{
     GeneralLanguageContext,
     InstallResult,
     UseResult,
+    CurrentResult,
+    LanguageEnv,
+    LanguageError,
+    ListFilter,
+    OutdatedVersion,
+    UpgradeResult,
     VenvResult,
 };
+pub use version_file::version_env_name;
 pub use golang::GolangExecutor;
 pub use node::NodeExecutor;
-pub use java::{JavaExecutor, JavaLanguageContext};
\ No newline at end of file
+pub use java::{JavaExecutor, JavaLanguageContext, Vendor};
\ No newline at end of file
@@ -1,4 +1,5 @@
-use crate::tool::http::{self, HttpError};
+use crate::tool::cache::IndexCache;
+use crate::tool::http::HttpError;
 use serde::Deserialize;
 use serde_json::{self, Error as JsonError, Value};
 use thiserror::Error;
@@ -6,20 +7,29 @@ use thiserror::Error;
 pub type Result<T> = std::result::Result<T, HtmlError>;
 
 pub async fn parse_node_official(
-    url: &'static str,
+    cache: &IndexCache,
+    urls: &[String],
     proxy: &Option<String>,
 ) -> Result<Vec<VersionItem>> {
-    let content = http::download_html(&url.to_string(), proxy).await?;
-    let data: Vec<VersionItem> = serde_json::from_str(content.as_str())?;
+    let data = cache
+        .download_html_from(urls, proxy, |content| serde_json::from_str::<Vec<VersionItem>>(content))
+        .await?;
     Ok(data)
 }
 
 #[derive(Deserialize, Debug)]
 pub struct VersionItem {
     pub version: String,
+    /// release date, e.g. `2024-01-09`.
+    pub date: Option<String>,
     pub files: Vec<String>,
     pub npm: Option<String>,
+    /// `NODE_MODULE_VERSION` of native addons, e.g. `115`.
+    pub modules: Option<String>,
     lts: Value,
+    /// whether it's a security release.
+    #[serde(default)]
+    pub security: bool,
 }
 
 impl VersionItem {
@@ -30,6 +40,11 @@ impl VersionItem {
             _ => false,
         }
     }
+
+    /// codename of an LTS release, e.g. `Iron`.
+    pub fn lts_name(&self) -> Option<&str> {
+        self.lts.as_str()
+    }
 }
 
 #[derive(Error, Debug)]
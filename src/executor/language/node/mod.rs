@@ -1,6 +1,6 @@
 mod node_executor;
 mod html;
 mod version;
-mod script;
+mod package_json;
 
 pub use node_executor::NodeExecutor;
\ No newline at end of file
@@ -1,21 +1,25 @@
 use super::super::language_executor::{
-    format_semver, ExecutorContext, LanguageError, LanguageExecutor, LanguageVersion, Result,
+    format_semver, ExecutorContext, LanguageError, LanguageExecutor, LanguageVersion,
+    PackageInfo, ProjectVersion, ReleaseInfo, Result,
 };
-use super::html::{parse_node_official, VersionItem};
-use super::script::generate_scripts;
-use super::version::NodeVersion;
+use super::html::{parse_node_official, HtmlError, VersionItem};
+use super::super::resolver::VersionResolver;
+use super::package_json::find_package_json;
+use super::version::{NodeAlias, NodeVersion};
 use crate::executor::language::language_executor::{GeneralLanguageContext, InstallResult};
+use crate::tool::cache::IndexCache;
 use crate::tool::checksum;
 use crate::tool::logger::{debug, error, info};
+use crate::tool::mirror::Mirror;
+use crate::tool::http::HttpError;
 use crate::tool::{fs, http, SupportedLanguage, NODE};
-use semver::VersionReq;
 use std::collections::HashMap;
 use std::fmt::Write;
 use std::fs::{remove_dir_all, remove_file, rename};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::runtime;
 
-static OFFICIAL_URL: &str = "https://nodejs.org/dist/index.json";
+static OFFICIAL_URL: &str = "https://nodejs.org/dist/";
 
 pub struct NodeExecutor {
     language: &'static SupportedLanguage,
@@ -36,25 +40,87 @@ impl NodeExecutor {
         Ok(result)
     }
 
+    /// get all releases from the index of the official site or the mirror, sorted by version.
+    fn get_versions(&self, context: &NodeContext) -> Result<Vec<NodeVersion>> {
+        self.get_versions_from(&context.index_cache, context)
+    }
+
+    /// get releases from the cached index only, it's never downloaded,
+    /// used where versions are resolved locally, e.g. by shims and the shell hook.
+    fn get_cached_versions(&self, context: &NodeContext) -> Result<Vec<NodeVersion>> {
+        let mut cache = context.index_cache.clone();
+        cache.set_offline(true);
+        self.get_versions_from(&cache, context)
+    }
+
+    fn get_versions_from(&self, cache: &IndexCache, context: &NodeContext) -> Result<Vec<NodeVersion>> {
+        let mirror = Mirror::new(OFFICIAL_URL, context.mirror.as_ref());
+        let version_items: Vec<VersionItem> = match self
+            .rt
+            .block_on(parse_node_official(cache, &mirror.urls("index.json"), &context.proxy))
+        {
+            Ok(vs) => vs,
+            Err(HtmlError::Http(e @ HttpError::NotCached(_))) => return Err(e.into()),
+            Err(e) => {
+                error!("failed to parse node version list: {}", e);
+                return Err(LanguageError::Html());
+            }
+        };
+        let mut versions: Vec<NodeVersion> = version_items.iter().filter_map(NodeVersion::from).collect();
+        versions.sort();
+        Ok(versions)
+    }
+
+    /// find the release matched by `version` given by user, which may be an alias like `lts/iron`,
+    /// the latest LTS release matching `version` is preferred if `--lts` is given.
+    fn find_release<'v>(&self, version: &str, versions: &'v [NodeVersion], context: &NodeContext) -> Result<Option<&'v NodeVersion>> {
+        let alias = NodeAlias::parse(version);
+        let prerelease = context.language_context.as_ref().is_some_and(|c| c.prerelease);
+        let resolver = match VersionResolver::parse(version, |s| semver::Version::parse(s.trim_start_matches('v')).ok()) {
+            _ if alias.is_some() => None,
+            Some(r) => Some(r.with_prerelease(prerelease)),
+            None => {
+                return Err(LanguageError::GeneralString(format!("cannot parse the given version({})", version)));
+            },
+        };
+        let prefer_lts = context.language_context.as_ref().is_some_and(|c| c.lts);
+        debug!("version request {:?}, alias: {:?}, prefer lts: {}", resolver, alias, prefer_lts);
+        let matched_version = match (&alias, &resolver) {
+            (Some(alias), _) => versions.iter().rev().find(|v| alias.matches(v)),
+            (None, Some(resolver)) => prefer_lts
+                .then(|| resolver.resolve(versions.iter().filter(|v| v.lts)))
+                .flatten()
+                .or_else(|| resolver.resolve(versions)),
+            (None, None) => None,
+        };
+        Ok(matched_version)
+    }
+
     fn get_checksum(
         &self,
-        url: &String,
+        urls: &[String],
         file_name: &String,
-        proxy: &Option<String>,
+        context: &NodeContext,
     ) -> Result<Option<String>> {
-        debug!("get checksum from {} for {}", url, file_name);
-        let bs = self.rt.block_on(http::download_html(url, proxy))?;
-        debug!("checksum file content: {}", &bs);
-        let digests = bs
-            .lines()
-            .map(|line| {
-                line.split(&[' ', '\t'])
-                    .filter(|s| !s.is_empty())
-                    .collect::<Vec<_>>()
-            })
-            .filter(|p| p.len() > 1)
-            .map(|v| (v[1], v[0]))
-            .collect::<HashMap<_, _>>();
+        debug!("get checksum from {:?} for {}", urls, file_name);
+        // checksum files never change, they are cached to verify downloaded archives in offline mode
+        let digests = self.rt.block_on(context.index_cache.download_html_from(urls, &context.proxy, |content| {
+            let digests: HashMap<_, _> = content
+                .lines()
+                .map(|line| {
+                    line.split(&[' ', '\t'])
+                        .filter(|s| !s.is_empty())
+                        .collect::<Vec<_>>()
+                })
+                .filter(|p| p.len() > 1)
+                .map(|v| (v[1].to_string(), v[0].to_string()))
+                .collect();
+            if digests.is_empty() {
+                Err("no checksum is found")
+            } else {
+                Ok(digests)
+            }
+        }))?;
 
         debug!("checksum map: {:?}", &digests);
         match digests.get(file_name.as_str()) {
@@ -71,62 +137,31 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
         self.language.name
     }
 
-    fn get_env_hint(&self, current_dir: &Path) -> String {
-        let current_dir = current_dir.join("current");
-        if cfg!(target_os="windows") {
-            format!(r###"please add `{0:?}` to your path."###, current_dir)
-        } else {
-            format!(r###"please add `{0:?}` to your path.
-            for example:
-            export PATH="{0:?}:$PATH"
-            "###, current_dir.as_os_str())
-        }
-    }
-
     fn list(&self, local_only: bool, context: &'a NodeContext<'a>) -> Result<Vec<LanguageVersion>> {
+        if context.filter.stable.is_some() {
+            return Err(LanguageError::General("`--stable` and `--unstable` are only supported by go"));
+        }
         let installed_version_map = self.get_installed_versions(context)?;
         if local_only {
-            let installed_versions: Vec<_> = installed_version_map
-                .into_iter()
-                .map(|(k, v)| LanguageVersion {
-                    version: k,
-                    installed: true,
-                    inuse: v,
-                })
-                .collect();
-            return Ok(installed_versions);
+            return self.list_installed(context);
         }
-        let version_items: Vec<VersionItem> = match self
-            .rt
-            .block_on(parse_node_official(OFFICIAL_URL, &context.proxy))
-        {
-            Ok(vs) => vs,
-            Err(e) => {
-                error!("failed to parse node version list: {}", e);
-                return Err(LanguageError::Html());
-            }
-        };
-
-        // println!("versions: {:?}", version_items);
-        let filter = match context.filter {
-            Some(v) => v,
-            None => "",
-        };
-        let filter_re = regex::RegexBuilder::new(&regex::escape(filter))
-            .case_insensitive(true)
-            .build()
-            .unwrap();
+        let filter = &context.filter;
+        let filter_re = filter.text_regex();
+        let sys_arch = context.arch.to_lowercase();
+        let arch = convert_to_node_arch(sys_arch.as_str());
+        let os = convert_to_node_os(context.os);
 
-        let mut versions: Vec<NodeVersion> = version_items
-            .iter()
-            .map(NodeVersion::from)
-            .filter(|o| {
-                o.as_ref()
-                    .is_some_and(|f| filter.is_empty() || f.is_match(&filter_re))
+        let versions: Vec<NodeVersion> = self
+            .get_versions(context)?
+            .into_iter()
+            .filter(|v| {
+                filter_re.as_ref().is_none_or(|re| v.is_match(re))
+                    && (!filter.installable || v.has_matched_package(os, arch))
+                    && filter.matches_major(&v.sem_version)
+                    && (!filter.lts || v.lts)
             })
-            .map(|o| o.expect(""))
             .collect();
-        versions.sort();
+        let versions = filter.keep_latest_per_minor(versions, |v| Some((v.sem_version.major, v.sem_version.minor)));
 
         let returned: Vec<LanguageVersion> = versions
             .into_iter()
@@ -145,53 +180,15 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
     }
 
     fn install(&self, version: String, context: &'a NodeContext<'a>) -> Result<InstallResult> {
-        let req = match VersionReq::parse(version.as_str()) {
-            Ok(r) => r,
-            Err(e) => {
-                return Err(LanguageError::GeneralString(format!("cannot parse the given version({}): {}", version, e)));
-            },
-        };
-        let version_items: Vec<VersionItem> = match self
-            .rt
-            .block_on(parse_node_official(OFFICIAL_URL, &context.proxy))
-        {
-            Ok(vs) => vs,
-            Err(e) => {
-                error!("failed to parse node version list: {}", e);
-                return Err(LanguageError::Html());
-            }
-        };
-
-        let mut versions: Vec<NodeVersion> =
-            version_items.iter().filter_map(NodeVersion::from).collect();
-        versions.sort();
+        let mirror = Mirror::new(OFFICIAL_URL, context.mirror.as_ref());
+        let versions = self.get_versions(context)?;
 
-        let sys_arch = std::env::consts::ARCH.to_lowercase();
+        let sys_arch = context.arch.to_lowercase();
         let arch = convert_to_node_arch(sys_arch.as_str());
-        let sys_os = std::env::consts::OS.to_lowercase();
+        let sys_os = context.os.to_lowercase();
         let os = convert_to_node_os(sys_os.as_str());
         debug!("current arch:{}, os:{}", arch, os);
-        let mut matched_version: Option<NodeVersion> = None;
-        let mut exact_matched_version: Option<NodeVersion> = None;
-        debug!("version request {}", req);
-        let node_ver = format!("v{}", version);
-        for item in versions.into_iter().rev() {
-            let sem_version = &item.sem_version;
-            // debug!("test with version: {}", version);
-            if item.version == node_ver {
-                exact_matched_version = Some(item);
-                break;
-            }
-            if exact_matched_version.is_none()
-                && matched_version.is_none()
-                && req.matches(sem_version)
-            {
-                matched_version = Some(item);
-            }
-        }
-        if exact_matched_version.is_some() {
-            matched_version = exact_matched_version;
-        }
+        let matched_version = self.find_release(&version, &versions, context)?;
         debug!("found matched version: {:?}", matched_version);
 
         match matched_version {
@@ -219,14 +216,12 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
                                     format!("{}.{}", file_name, package.kind.to_str());
                                 let downloaded_file = download_dir.join(&archive_name);
 
-                                let checksum_url = format!(
-                                    "https://nodejs.org/dist/v{}/SHASUMS256.txt",
-                                    &version_str
-                                );
+                                let checksum_urls = mirror
+                                    .urls(&format!("v{}/SHASUMS256.txt", &version_str));
                                 let checksum = self.get_checksum(
-                                    &checksum_url,
+                                    &checksum_urls,
                                     &archive_name,
-                                    &context.proxy,
+                                    context,
                                 )?;
                                 let mut need_download = true;
                                 if downloaded_file.exists() && downloaded_file.is_file() {
@@ -248,20 +243,22 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
                                 }
                                 let versions = context.language_dir.get_versions_dir();
                                 let archived_dir = versions.join(&version_str);
+                                if need_download && context.offline {
+                                    return Err(LanguageError::Offline(archive_name));
+                                }
                                 if need_download {
-                                    let url = format!(
-                                        "https://nodejs.org/dist/v{}/{}",
-                                        &version_str, &archive_name
-                                    );
-                                    debug!("download file({}) to {:?}", &url, &downloaded_file);
-                                    self.rt.block_on(http::download_file(
-                                        &url,
+                                    let urls = mirror
+                                        .urls(&format!("v{}/{}", &version_str, &archive_name));
+                                    debug!("download file({:?}) to {:?}", &urls, &downloaded_file);
+                                    let hasher = self.rt.block_on(http::download_file_from(
+                                        &urls,
                                         &downloaded_file,
                                         &context.proxy,
+                                        &checksum::ChecksumMethod::Sha256,
                                     ))?;
                                     match &checksum {
                                         Some(c) => {
-                                            if !self.verify_file(&downloaded_file, c)? {
+                                            if !hasher.verify(c) {
                                                 error!("failed to verify the downloaded file");
                                                 return Err(LanguageError::FailedToVerify());
                                             }
@@ -282,6 +279,9 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
 
                                 debug!("rename go to version");
                                 rename(&node_dir, &archived_dir)?;
+                                if !context.keep_archives {
+                                    remove_file(&downloaded_file)?;
+                                }
 
                                 let mut need_hint = false;
                                 if !context.language_context.as_ref().is_some_and(|c| c.no_use) {
@@ -295,7 +295,6 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
                                     }
                                     fs::make_link(current_dir, &archived_dir)?;
                                 }
-                                remove_file(downloaded_file)?;
                                 if need_hint {
                                     Ok(InstallResult::SuccessNeedHint)
                                 } else {
@@ -306,19 +305,95 @@ impl<'a> LanguageExecutor<'a, GeneralLanguageContext> for NodeExecutor {
                         Err(_) => Err(LanguageError::FailedToReadFS()),
                     }
                 } else {
-                    Err(LanguageError::NoMatchedArchOrOS(
-                        arch.to_string(),
-                        os.to_string(),
-                    ))
+                    Err(LanguageError::no_matched_platform(arch, os, version.platforms()))
                 }
             }
             None => Err(LanguageError::NoSuchVersion(version)),
         }
     }
 
-    fn post_venv(&self, dir: &Path) -> Result<()> {
-        generate_scripts(dir)?;
-        Ok(())
+    /// resolve aliases like `latest`, `lts` and `lts/iron` to the latest installed version of them,
+    /// LTS releases are found in the cached release index, which is downloaded by `install` and `list`.
+    fn resolve_installed_version(&self, version: String, context: &NodeContext) -> Result<String> {
+        let alias = match NodeAlias::parse(&version) {
+            Some(a) => a,
+            None => return Ok(version),
+        };
+        let mut installed: Vec<_> = self
+            .get_installed_versions(context)?
+            .into_keys()
+            .filter_map(|n| semver::Version::parse(&n).ok())
+            .collect();
+        if alias.requires_index() {
+            let releases = self.get_cached_versions(context)?;
+            installed.retain(|v| releases.iter().any(|r| &r.sem_version == v && alias.matches(r)));
+        } else {
+            installed.retain(|v| v.pre.is_empty());
+        }
+        Ok(installed.into_iter().max().map(|v| format_semver(&v)).unwrap_or(version))
+    }
+
+    fn list_releases(&self, context: &NodeContext) -> Result<Vec<String>> {
+        let sys_arch = context.arch.to_lowercase();
+        let arch = convert_to_node_arch(sys_arch.as_str());
+        let os = convert_to_node_os(context.os);
+        let result = self
+            .get_versions(context)?
+            .into_iter()
+            .filter(|v| v.sem_version.pre.is_empty() && v.has_matched_package(os, arch))
+            .map(|v| format_semver(&v.sem_version))
+            .collect();
+        Ok(result)
+    }
+
+    /// newer minor releases of the same major version are upgrades, e.g. `20.11.0` of `20.10.0`.
+    fn release_line(&self, version: &semver::Version) -> (u64, u64) {
+        (version.major, 0)
+    }
+
+    fn info(&self, version: String, context: &NodeContext) -> Result<ReleaseInfo> {
+        let versions = self.get_versions(context)?;
+        let release = match self.find_release(&version, &versions, context)? {
+            Some(r) => r,
+            None => return Err(LanguageError::NoSuchVersion(version)),
+        };
+        let name = format_semver(&release.sem_version);
+        let unknown = || "unknown".to_string();
+        let details = vec![
+            ("npm", release.npm_version.clone().unwrap_or_else(unknown)),
+            ("lts", release.lts_name.clone().unwrap_or_else(|| "no".to_string())),
+            ("date", release.date.clone().unwrap_or_else(unknown)),
+            ("modules", release.modules.clone().unwrap_or_else(unknown)),
+            ("security", if release.security { "yes" } else { "no" }.to_string()),
+        ];
+        let packages = release
+            .packages
+            .iter()
+            .map(|p| PackageInfo {
+                platform: format!("{}/{}", p.os, p.arch).to_lowercase(),
+                kind: p.kind.to_str().to_string(),
+                details: vec![],
+            })
+            .collect();
+        Ok(ReleaseInfo {
+            installed_dir: self.get_installed_dir(&name, context)?,
+            name,
+            details,
+            packages,
+        })
+    }
+
+    fn get_project_version(&self, current_dir: &Path) -> Result<Option<ProjectVersion>> {
+        find_package_json(current_dir)
+    }
+
+    /// executables are in the root folder on Windows.
+    fn get_bin_dir(&self, version_dir: &Path) -> PathBuf {
+        if cfg!(windows) {
+            version_dir.to_path_buf()
+        } else {
+            version_dir.join("bin")
+        }
     }
 }
 
@@ -372,3 +447,46 @@ pub fn format_version(version: &NodeVersion) -> String {
     }
     base
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::super::language_executor::{ExecutorContext, LanguageError, LanguageExecutor, ListFilter};
+    use super::NodeExecutor;
+    use crate::tool::cache::IndexCache;
+    use crate::tool::fs::LanguageDir;
+    use crate::tool::http::HttpError;
+    use crate::tool::test_dir::TestDir;
+    use std::collections::BTreeMap;
+    use std::fs;
+
+    #[test]
+    fn test_resolve_installed_version() {
+        let test_dir = TestDir::new("node_resolve_installed");
+        let root = test_dir.path();
+        let language_dir = LanguageDir::new(root, "node").unwrap();
+        for name in ["20.10.0", "20.11.0", "21.0.0-rc.1"] {
+            fs::create_dir_all(language_dir.get_versions_dir().join(name)).unwrap();
+        }
+        // nothing listens on the discard port, the index must not be downloaded anyway
+        let context = ExecutorContext {
+            proxy: None,
+            mirror: Some("http://127.0.0.1:9/".to_string()),
+            language_dir: &language_dir,
+            shims_dir: root,
+            language_context: None,
+            filter: ListFilter::default(),
+            aliases: BTreeMap::new(),
+            index_cache: IndexCache::new(&root.join("cache"), 0),
+            offline: false,
+            keep_archives: false,
+            arch: "x86_64",
+            os: "linux",
+        };
+        let executor = NodeExecutor::new();
+        let resolve = |v: &str| executor.resolve_installed_version(v.to_string(), &context);
+        assert_eq!("20.11.0", resolve("latest").unwrap());
+        assert_eq!("20.10", resolve("20.10").unwrap());
+        assert!(matches!(resolve("lts"), Err(LanguageError::Http(HttpError::NotCached(_)))));
+        assert!(matches!(resolve("lts/iron"), Err(LanguageError::Http(HttpError::NotCached(_)))));
+    }
+}
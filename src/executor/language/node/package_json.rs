@@ -0,0 +1,66 @@
+use super::super::language_executor::{ProjectVersion, Result};
+use super::super::resolver::parse_range;
+use crate::tool::logger::error;
+use serde_json::Value;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub static PACKAGE_JSON_FILE: &str = "package.json";
+
+/// find the nearest `package.json` which has `engines.node` from `current_dir`.
+pub fn find_package_json(current_dir: &Path) -> Result<Option<ProjectVersion>> {
+    for dir in current_dir.ancestors() {
+        let path = dir.join(PACKAGE_JSON_FILE);
+        if !path.is_file() {
+            continue;
+        }
+        // a malformed package.json, e.g. a fixture of tests, should not hide those of upper folders
+        let package: Value = match serde_json::from_str(&read_to_string(&path)?) {
+            Ok(p) => p,
+            Err(err) => {
+                error!("failed to parse {:?}, skip it: {}", path, err);
+                continue;
+            }
+        };
+        let range = match package.pointer("/engines/node").and_then(|v| v.as_str()) {
+            Some(r) => r.trim().to_string(),
+            None => continue,
+        };
+        let reqs = match parse_range(&range) {
+            Some(r) => r,
+            None => continue,
+        };
+        let hint = package
+            .get("packageManager")
+            .and_then(|v| v.as_str())
+            .map(|pm| format!("package manager {} is required, enable it by `corepack enable`", pm));
+        return Ok(Some(ProjectVersion { path, version: range, reqs, hint }));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_package_json;
+    use crate::tool::test_dir::TestDir;
+    use std::fs;
+
+    #[test]
+    fn test_find_package_json() {
+        let test_dir = TestDir::new("find_package_json");
+        let root = test_dir.path();
+        let nested = root.join("packages").join("web");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("package.json"), r#"{"name": "web"}"#).unwrap();
+        fs::write(root.join("packages").join("package.json"), "{").unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"engines": {"node": ">=18"}, "packageManager": "pnpm@8.6.0"}"#,
+        )
+        .unwrap();
+        let project = find_package_json(&nested).unwrap().unwrap();
+        assert_eq!(root.join("package.json"), project.path);
+        assert_eq!(">=18", project.version);
+        assert!(project.hint.is_some_and(|h| h.contains("pnpm@8.6.0")));
+    }
+}
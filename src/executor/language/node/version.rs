@@ -1,3 +1,5 @@
+use super::super::language_executor::archive_platforms;
+use super::super::resolver::ResolvableVersion;
 use super::html::VersionItem;
 use semver::Version;
 use std::cmp::Ordering;
@@ -7,7 +9,12 @@ pub struct NodeVersion {
     pub version: String,
     pub sem_version: Version,
     pub npm_version: Option<String>,
+    pub date: Option<String>,
+    pub modules: Option<String>,
+    pub security: bool,
     pub lts: bool,
+    /// codename of an LTS release in lower case, e.g. `iron`.
+    pub lts_name: Option<String>,
     pub packages: Vec<NodePackage>,
 }
 
@@ -36,7 +43,11 @@ impl NodeVersion {
                 version: version_item.version.clone(),
                 sem_version,
                 npm_version: version_item.npm.clone(),
+                date: version_item.date.clone(),
+                modules: version_item.modules.clone(),
+                security: version_item.security,
                 lts: version_item.is_lts(),
+                lts_name: version_item.lts_name().map(|n| n.to_lowercase()),
                 packages,
             })
         } else {
@@ -60,6 +71,11 @@ impl NodeVersion {
         false
     }
 
+    /// platforms which have an archive package, like `linux/x64`.
+    pub fn platforms(&self) -> Vec<String> {
+        archive_platforms(self.packages.iter().map(|p| (p.os.as_str(), p.arch.as_str(), p.kind.is_archive())))
+    }
+
     pub fn get_matched_package(&self, os: &str, arch: &str) -> Option<&NodePackage> {
         self.packages.iter().find(|package| {
             package.arch.to_lowercase() == arch
@@ -69,6 +85,50 @@ impl NodeVersion {
     }
 }
 
+impl ResolvableVersion for NodeVersion {
+    fn is_named(&self, spec: &str) -> bool {
+        self.version.trim_start_matches('v') == spec.trim_start_matches('v')
+    }
+
+    fn sem_version(&self) -> &Version {
+        &self.sem_version
+    }
+}
+
+/// NodeAlias is a symbolic version, like `latest`, `lts` and `lts/iron`.
+#[derive(Debug, PartialEq)]
+pub enum NodeAlias {
+    Latest,
+    /// the latest LTS release, or the latest release of an LTS codename.
+    Lts(Option<String>),
+}
+
+impl NodeAlias {
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.to_lowercase();
+        match version.as_str() {
+            "latest" | "node" => Some(Self::Latest),
+            "lts" | "lts/*" => Some(Self::Lts(None)),
+            _ => version
+                .strip_prefix("lts/")
+                .map(|name| Self::Lts(Some(name.to_string()))),
+        }
+    }
+
+    pub fn matches(&self, version: &NodeVersion) -> bool {
+        match self {
+            Self::Latest => version.sem_version.pre.is_empty(),
+            Self::Lts(None) => version.lts,
+            Self::Lts(Some(name)) => version.lts_name.as_ref() == Some(name),
+        }
+    }
+
+    /// whether the release index is required to resolve the alias.
+    pub fn requires_index(&self) -> bool {
+        !matches!(self, Self::Latest)
+    }
+}
+
 impl Ord for NodeVersion {
     fn cmp(&self, other: &Self) -> Ordering {
         self.sem_version.cmp(&other.sem_version)
@@ -128,3 +188,74 @@ impl PackageKind {
         *self == Self::Zip || *self == Self::Zip7z || *self == Self::Tar
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::html::VersionItem;
+    use super::super::super::resolver::VersionResolver;
+    use super::{NodeAlias, NodeVersion};
+
+    fn versions(names: &[&str]) -> Vec<NodeVersion> {
+        names
+            .iter()
+            .map(|n| {
+                let item: VersionItem = serde_json::from_str(&format!(r#"{{"version":"{}","files":[],"lts":false}}"#, n)).unwrap();
+                NodeVersion::from(&item).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_from_item() {
+        let item: VersionItem = serde_json::from_str(
+            r#"{"version":"v20.11.0","date":"2024-01-09","files":["linux-x64","win-x64-zip"],
+            "npm":"10.2.4","modules":"115","lts":"Iron","security":true}"#,
+        )
+        .unwrap();
+        let version = NodeVersion::from(&item).unwrap();
+        assert_eq!(Some("2024-01-09".to_string()), version.date);
+        assert_eq!(Some("115".to_string()), version.modules);
+        assert_eq!(Some("iron".to_string()), version.lts_name);
+        assert!(version.security);
+
+        let item: VersionItem = serde_json::from_str(r#"{"version":"v0.1.14","files":[],"npm":null,"lts":false}"#).unwrap();
+        let version = NodeVersion::from(&item).unwrap();
+        assert_eq!(None, version.date);
+        assert!(!version.security);
+    }
+
+    #[test]
+    fn test_parse_alias() {
+        assert_eq!(Some(NodeAlias::Latest), NodeAlias::parse("latest"));
+        assert_eq!(Some(NodeAlias::Lts(None)), NodeAlias::parse("lts/*"));
+        assert_eq!(Some(NodeAlias::Lts(Some("iron".to_string()))), NodeAlias::parse("LTS/Iron"));
+        assert_eq!(None, NodeAlias::parse("20"));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let vs = versions(&["v16.20.2", "v18.19.0", "v20.10.0", "v20.11.0", "v21.0.0-rc.1"]);
+        let cases = [
+            // (spec, prerelease, expected)
+            ("20", false, Some("v20.11.0")),
+            ("v20", false, Some("v20.11.0")),
+            ("20.10.0", false, Some("v20.10.0")),
+            ("v20.10.0", false, Some("v20.10.0")),
+            ("^16 || ^18", false, Some("v18.19.0")),
+            (">=16 <20", false, Some("v18.19.0")),
+            ("16 - 18", false, Some("v18.19.0")),
+            ("18.x", false, Some("v18.19.0")),
+            ("21", false, None),
+            ("21", true, Some("v21.0.0-rc.1")),
+            ("22", false, None),
+        ];
+        for (spec, pre, expected) in cases {
+            let resolved = VersionResolver::parse(spec, |s| semver::Version::parse(s.trim_start_matches('v')).ok())
+                .unwrap()
+                .with_prerelease(pre)
+                .resolve(&vs)
+                .map(|v| v.version.as_str());
+            assert_eq!(expected, resolved, "spec: {}", spec);
+        }
+    }
+}
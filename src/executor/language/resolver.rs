@@ -0,0 +1,227 @@
+use semver::{Prerelease, Version, VersionReq};
+
+/// ResolvableVersion is a release or an installed version which can be resolved by `VersionResolver`.
+pub trait ResolvableVersion {
+    /// whether `spec` is the exact name of the version, e.g. `1.21rc1` of go.
+    fn is_named(&self, spec: &str) -> bool;
+    fn sem_version(&self) -> &Version;
+}
+
+/// InstalledVersion is a version in the versions dir of a language.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstalledVersion {
+    /// name of the version dir, e.g. `corretto-21.0.1.12.1`.
+    pub name: String,
+    /// version part of the name, e.g. `21.0.1.12.1`.
+    pub version: String,
+    pub sem_version: Version,
+}
+
+impl ResolvableVersion for InstalledVersion {
+    fn is_named(&self, spec: &str) -> bool {
+        self.name == spec || self.version == spec
+    }
+
+    fn sem_version(&self) -> &Version {
+        &self.sem_version
+    }
+}
+
+/// VersionResolver picks a version for a spec, which may be
+///   * an exact version, e.g. `1.21.6`, `v20.10.0`, or a language-specific one like `1.21rc1`
+///   * a partial version, e.g. `1.21` or `1.21.x` for the latest `1.21.x`
+///   * a range, e.g. `~1.21.0`, `^20`, `>=1.20 <1.22` or `^16 || ^18`
+///
+/// pre-releases only match ranges which contain a pre-release of the same version,
+/// unless they are opted in by `with_prerelease`.
+#[derive(Clone, Debug)]
+pub struct VersionResolver {
+    spec: String,
+    reqs: Vec<VersionReq>,
+    exact: Option<Version>,
+    prerelease: bool,
+}
+
+impl VersionResolver {
+    /// parse `spec` as a range, `parse_exact` parses language-specific versions which are not valid ranges.
+    pub fn parse<F: Fn(&str) -> Option<Version>>(spec: &str, parse_exact: F) -> Option<Self> {
+        let spec = spec.trim();
+        let (reqs, exact) = match parse_range(spec) {
+            Some(reqs) => (reqs, None),
+            None => (vec![], Some(parse_exact(spec)?)),
+        };
+        Some(VersionResolver {
+            spec: spec.to_string(),
+            reqs,
+            exact,
+            prerelease: false,
+        })
+    }
+
+    /// let pre-releases match ranges of their release, e.g. `1.22.0-rc1` matches `1.22`.
+    pub fn with_prerelease(mut self, prerelease: bool) -> Self {
+        self.prerelease = prerelease;
+        self
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        if let Some(exact) = &self.exact {
+            return exact == version;
+        }
+        let release = (self.prerelease && !version.pre.is_empty()).then(|| Version {
+            pre: Prerelease::EMPTY,
+            ..version.clone()
+        });
+        self.reqs
+            .iter()
+            .any(|r| r.matches(version) || release.as_ref().is_some_and(|v| r.matches(v)))
+    }
+
+    /// get the latest version matching the spec, a spec which is not a range like `1.21rc1`
+    /// picks the version named by it.
+    pub fn resolve<'v, V: ResolvableVersion, I: IntoIterator<Item = &'v V>>(&self, versions: I) -> Option<&'v V> {
+        let mut result: Option<&V> = None;
+        for version in versions {
+            if self.exact.is_some() && version.is_named(&self.spec) {
+                return Some(version);
+            }
+            if self.matches(version.sem_version())
+                && result.is_none_or(|r| version.sem_version() > r.sem_version())
+            {
+                result = Some(version);
+            }
+        }
+        result
+    }
+}
+
+/// convert a range like `>=18 <21 || ^16.20`, to requirements, a version satisfies the range
+/// if it matches any of them, versions without operator like `1.21` match the version exactly
+/// or the latest patch/minor release of it, which is different from the default caret of `semver`.
+pub fn parse_range(range: &str) -> Option<Vec<VersionReq>> {
+    let mut result = Vec::new();
+    for part in range.split("||") {
+        let tokens: Vec<_> = part.split_whitespace().collect();
+        let comparators = match tokens.as_slice() {
+            [] => vec!["*".to_string()],
+            [from, "-", to] => vec![
+                format!(">={}", from.trim_start_matches('v')),
+                format!("<={}", to.trim_start_matches('v')),
+            ],
+            _ => {
+                let mut comparators = Vec::new();
+                let mut op = String::new();
+                for token in tokens {
+                    if token.chars().all(|c| "<>=~^".contains(c)) {
+                        op.push_str(token);
+                        continue;
+                    }
+                    let version = token.trim_start_matches(|c| "<>=~^".contains(c));
+                    let mut token_op = format!("{}{}", op, &token[..token.len() - version.len()]);
+                    let version = version.trim_start_matches('v');
+                    if token_op.is_empty() && !version.contains(['*', 'x', 'X']) {
+                        token_op.push('=');
+                    }
+                    comparators.push(format!("{}{}", token_op, version));
+                    op.clear();
+                }
+                comparators
+            }
+        };
+        result.push(VersionReq::parse(&comparators.join(", ")).ok()?);
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_range, InstalledVersion, VersionResolver};
+    use semver::Version;
+
+    fn matches(range: &str, version: &str) -> bool {
+        let v = Version::parse(version).unwrap();
+        parse_range(range).unwrap().iter().any(|r| r.matches(&v))
+    }
+
+    fn installed(names: &[&str]) -> Vec<InstalledVersion> {
+        names
+            .iter()
+            .map(|n| InstalledVersion {
+                name: n.to_string(),
+                version: n.to_string(),
+                sem_version: Version::parse(n).unwrap(),
+            })
+            .collect()
+    }
+
+    fn resolve(spec: &str, names: &[&str], prerelease: bool) -> Option<String> {
+        let versions = installed(names);
+        let resolver = VersionResolver::parse(spec, |s| Version::parse(s).ok())?.with_prerelease(prerelease);
+        resolver.resolve(&versions).map(|v| v.name.clone())
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert!(matches(">=18 <21", "20.10.0"));
+        assert!(!matches(">=18 <21", "21.0.0"));
+        assert!(matches(">= 18.17.0", "18.17.1"));
+        assert!(matches("^16.20 || ^18", "16.20.2"));
+        assert!(!matches("^16.20 || ^18", "17.0.0"));
+        assert!(matches("18.x", "18.19.0"));
+        assert!(matches("v18", "18.19.0"));
+        assert!(!matches("18", "19.0.0"));
+        assert!(matches("16 - 18", "18.19.0"));
+        assert!(!matches("16 - 18", "19.0.0"));
+        assert!(matches("*", "21.5.0"));
+        assert!(matches("1.21", "1.21.6"));
+        assert!(!matches("1.21", "1.22.0"));
+        assert!(matches("1.21.6", "1.21.6"));
+        assert!(!matches("1.21.6", "1.21.7"));
+        assert!(parse_range("lts/*").is_none());
+        assert!(parse_range("1.21rc1").is_none());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let names = ["1.9.7", "1.20.14", "1.21.0", "1.21.6", "1.22.0-rc1", "1.22.0", "1.23.0-rc1"];
+        assert_eq!(Some("1.21.6".to_string()), resolve("1.21", &names, false));
+        assert_eq!(Some("1.21.6".to_string()), resolve("1.21.x", &names, false));
+        assert_eq!(Some("1.21.0".to_string()), resolve("1.21.0", &names, false));
+        assert_eq!(Some("1.21.6".to_string()), resolve("~1.21.0", &names, false));
+        assert_eq!(Some("1.22.0".to_string()), resolve("^1.20", &names, false));
+        assert_eq!(Some("1.21.6".to_string()), resolve(">=1.20 <1.22", &names, false));
+        assert_eq!(Some("1.9.7".to_string()), resolve("1.9", &names, false));
+        assert_eq!(Some("1.22.0".to_string()), resolve("*", &names, false));
+        assert_eq!(None, resolve("1.24", &names, false));
+
+        // pre-releases
+        assert_eq!(None, resolve("1.23", &names, false));
+        assert_eq!(Some("1.23.0-rc1".to_string()), resolve("1.23", &names, true));
+        assert_eq!(Some("1.22.0".to_string()), resolve("1.22", &names, true));
+        assert_eq!(Some("1.22.0-rc1".to_string()), resolve("1.22.0-rc1", &names, false));
+        assert_eq!(Some("1.23.0-rc1".to_string()), resolve(">=1.23.0-rc1", &names, false));
+    }
+
+    #[test]
+    fn test_resolve_exact() {
+        let versions = vec![InstalledVersion {
+            name: "corretto-21.0.1.12.1".to_string(),
+            version: "21.0.1.12.1".to_string(),
+            sem_version: Version::parse("21.0.1+12.1").unwrap(),
+        }];
+        let resolver = VersionResolver::parse("21.0.1.12.1", |_| Version::parse("21.0.1+12.1").ok()).unwrap();
+        assert_eq!(Some(&versions[0]), resolver.resolve(&versions));
+        let resolver = VersionResolver::parse("1.21rc1", |_| Version::parse("1.21.0-rc1").ok()).unwrap();
+        assert!(resolver.matches(&Version::parse("1.21.0-rc1").unwrap()));
+        assert!(!resolver.matches(&Version::parse("1.21.0").unwrap()));
+        assert!(VersionResolver::parse("lts", |_| None).is_none());
+
+        // a name only picks its version if the spec is not a range
+        let versions = vec![
+            InstalledVersion { name: "1.20".to_string(), version: "1.20".to_string(), sem_version: Version::new(1, 20, 0) },
+            InstalledVersion { name: "1.20.14".to_string(), version: "1.20.14".to_string(), sem_version: Version::new(1, 20, 14) },
+        ];
+        let resolver = VersionResolver::parse("1.20", |_| None).unwrap();
+        assert_eq!(Some(&versions[1]), resolver.resolve(&versions));
+    }
+}
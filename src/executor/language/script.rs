@@ -0,0 +1,257 @@
+use super::LanguageEnv;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Error;
+use std::path::Path;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// generate scripts which activate and deactivate the virtual environment in `dir`,
+/// paths and variables come from `env`, paths inside `dir` are written relative to the scripts,
+/// missing directories of variables inside `dir` are created, e.g. `go_path` of Golang.
+pub fn generate_scripts(dir: &Path, name: &str, env: &LanguageEnv) -> Result<()> {
+    for (_, value) in &env.vars {
+        if value.starts_with(dir) && fs::symlink_metadata(value).is_err() {
+            fs::create_dir_all(value)?;
+        }
+    }
+    for (script, file_name) in get_scripts(dir, name, env) {
+        fs::write(dir.join(file_name), script)?;
+    }
+    Ok(())
+}
+
+/// `path` as a script expression, `root` is the expression of `dir`.
+fn script_path(dir: &Path, path: &Path, root: &str) -> String {
+    match path.strip_prefix(dir) {
+        Ok(rel) if rel.as_os_str().is_empty() => root.to_string(),
+        Ok(rel) => format!("{}{}{}", root, std::path::MAIN_SEPARATOR, rel.to_string_lossy()),
+        Err(_) => path.to_string_lossy().to_string(),
+    }
+}
+
+/// name of the variable which records the activated virtual environment, e.g. `UVM_VENV_GO_`.
+fn active_var(name: &str) -> String {
+    format!("UVM_VENV_{}_", name.to_uppercase())
+}
+
+#[cfg(target_os = "windows")]
+fn get_scripts(dir: &Path, name: &str, env: &LanguageEnv) -> [(String, &'static str); 2] {
+    [
+        (ps1_activate(dir, name, env), "activiate.ps1"),
+        (ps1_deactivate(dir, name, env), "deactiviate.ps1"),
+    ]
+}
+
+#[cfg(not(target_os = "windows"))]
+fn get_scripts(dir: &Path, name: &str, env: &LanguageEnv) -> [(String, &'static str); 2] {
+    [
+        (shell_activate(dir, name, env), "activiate.sh"),
+        (shell_deactivate(dir, name, env), "deactiviate.sh"),
+    ]
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn ps1_activate(dir: &Path, name: &str, env: &LanguageEnv) -> String {
+    let active = active_var(name);
+    let root = "$uvmVenvDir";
+    let mut setup = String::new();
+    for (var, value) in &env.vars {
+        let _ = writeln!(setup, "    if (Test-Path env:\\{0}) {{ $Env:{0}_PRE_ = $Env:{0} }}", var);
+        let _ = writeln!(setup, "    $Env:{} = \"{}\"", var, script_path(dir, value, root));
+    }
+    for path in env.paths.iter().rev() {
+        let _ = writeln!(setup, "    $Env:Path = \"{}\" + \";\" + $Env:Path", script_path(dir, path, root));
+    }
+    format!(
+        r###"$uvmVenvDir = $PSScriptRoot
+
+If (-not (Test-Path "$uvmVenvDir\{name}")) {{
+    Write-Host ""
+    Write-Host "ERROR: {name} is not installed in '$uvmVenvDir\{name}'."
+    Write-Host "Cannot activate {name} virtual environment."
+    Write-Host ""
+    return
+}}
+
+If (-not $Env:{active}) {{
+    $Env:{active} = $uvmVenvDir
+{setup}
+    # Adjust the prompt
+    #
+    # Note: borrowed this idea from the Python 2.7 activate.ps1 script
+    #
+    Function global:_old_virtual_prompt_{name} {{ "" }}
+    $Function:_old_virtual_prompt_{name} = $Function:prompt
+    Function global:prompt {{
+        # Add a prefix to the current prompt, but don't discard it.
+        Write-Host "({name}) " -nonewline
+        & $Function:_old_virtual_prompt_{name}
+    }}
+}}
+"###
+    )
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn ps1_deactivate(dir: &Path, name: &str, env: &LanguageEnv) -> String {
+    let active = active_var(name);
+    let root = "$uvmVenvDir";
+    let mut restore = String::new();
+    for path in &env.paths {
+        let _ = writeln!(
+            restore,
+            "$Env:Path = ($Env:Path.split(';') | Where-Object {{ $_ -ne \"{}\" }}) -Join ';'",
+            script_path(dir, path, root)
+        );
+    }
+    for (var, _) in &env.vars {
+        let _ = writeln!(
+            restore,
+            "If (Test-Path env:\\{0}_PRE_) {{\n    $Env:{0} = $Env:{0}_PRE_\n    Remove-Item env:\\{0}_PRE_\n}} Else {{\n    Remove-Item env:\\{0} -ErrorAction SilentlyContinue\n}}",
+            var
+        );
+    }
+    format!(
+        r###"If (-not $Env:{active}) {{
+    Write-Host "{name} virtual environment not active."
+    Write-Host ""
+    return
+}}
+$uvmVenvDir = $Env:{active}
+
+{restore}Remove-Item env:\{active}
+
+# Remove the virtual enviroment indication from the command prompt
+#
+If (Test-Path function:_old_virtual_prompt_{name}) {{
+    $function:prompt = $function:_old_virtual_prompt_{name}
+    Remove-Item function:\_old_virtual_prompt_{name}
+}}
+"###
+    )
+}
+
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn shell_activate(dir: &Path, name: &str, env: &LanguageEnv) -> String {
+    let active = active_var(name);
+    let root = "${UVM_VENV_DIR_}";
+    let mut setup = String::new();
+    for (var, value) in &env.vars {
+        let _ = writeln!(setup, "    if [[ -n \"${{{0}+x}}\" ]]; then\n        export {0}_PRE_=\"${{{0}}}\"\n    fi", var);
+        let _ = writeln!(setup, "    export {}=\"{}\"", var, script_path(dir, value, root));
+    }
+    for path in env.paths.iter().rev() {
+        let _ = writeln!(setup, "    export PATH=\"{}:${{PATH}}\"", script_path(dir, path, root));
+    }
+    format!(
+        r###"#!/bin/bash
+if [ "$0" = "$BASH_SOURCE" ]; then
+    echo "Error: Script must be sourced"
+    exit 1
+fi
+
+UVM_VENV_DIR_="$(cd "$(dirname "${{BASH_SOURCE[0]}}")" && pwd)"
+if [[ ! -d "${{UVM_VENV_DIR_}}/{name}" ]]; then
+    echo "ERROR: {name} is not installed at '${{UVM_VENV_DIR_}}/{name}'." > /dev/stderr
+    unset UVM_VENV_DIR_
+    return 2
+fi
+
+# If the virtual environment is not active, set variables, update PATH
+# and prefix the command prompt with '({name})'
+#
+if [[ -z "${{{active}}}" ]]; then
+    export {active}="${{UVM_VENV_DIR_}}"
+{setup}    export PS1="({name})${{PS1}}"
+fi
+unset UVM_VENV_DIR_
+"###
+    )
+}
+
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn shell_deactivate(dir: &Path, name: &str, env: &LanguageEnv) -> String {
+    let active = active_var(name);
+    let root = format!("${{{}}}", active);
+    let mut restore = String::new();
+    for path in &env.paths {
+        let _ = writeln!(
+            restore,
+            "    OLD_PATH_=\"{}\"\n    NEW_PATH_=\":${{PATH}}:\"\n    NEW_PATH_=\"${{NEW_PATH_//\":${{OLD_PATH_}}:\"/:}}\"\n    NEW_PATH_=\"${{NEW_PATH_#:}}\"\n    export PATH=\"${{NEW_PATH_%:}}\"",
+            script_path(dir, path, &root)
+        );
+    }
+    for (var, _) in &env.vars {
+        let _ = writeln!(
+            restore,
+            "    if [[ -n \"${{{0}_PRE_+x}}\" ]]; then\n        export {0}=\"${{{0}_PRE_}}\"\n        unset {0}_PRE_\n    else\n        unset {0}\n    fi",
+            var
+        );
+    }
+    format!(
+        r###"#!/bin/bash
+if [ "$0" = "$BASH_SOURCE" ]; then
+    echo "Error: Script must be sourced"
+    exit 1
+fi
+
+# Restore PATH, variables and the command prompt without a '({name})' prefix
+#
+if [[ -n "${{{active}}}" ]]; then
+{restore}    unset OLD_PATH_ NEW_PATH_
+    export PS1="$(echo "${{PS1}}" | sed -E 's/^(.*)\({name}\)(.*)$/\1\2/')"
+    unset {active}
+fi
+"###
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate_scripts, shell_activate, shell_deactivate};
+    use crate::executor::LanguageEnv;
+    use crate::tool::test_dir::TestDir;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_shell_scripts() {
+        let dir = Path::new("/project/.venv");
+        let env = LanguageEnv {
+            paths: vec![dir.join("go").join("bin")],
+            vars: vec![("GOROOT", dir.join("go")), ("GOPATH", PathBuf::from("/shared/go_path"))],
+        };
+        let activate = shell_activate(dir, "go", &env);
+        assert!(activate.contains("export GOROOT=\"${UVM_VENV_DIR_}/go\""));
+        assert!(activate.contains("export GOPATH=\"/shared/go_path\""));
+        assert!(activate.contains("export PATH=\"${UVM_VENV_DIR_}/go/bin:${PATH}\""));
+        let deactivate = shell_deactivate(dir, "go", &env);
+        assert!(deactivate.contains("OLD_PATH_=\"${UVM_VENV_GO_}/go/bin\""));
+        assert!(deactivate.contains("unset GOPATH"));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_restore_variables() {
+        let test_dir = TestDir::new("script_restore");
+        let dir = test_dir.path();
+        fs::create_dir(dir.join("go")).unwrap();
+        let env = LanguageEnv {
+            paths: vec![dir.join("go").join("bin")],
+            vars: vec![("GOROOT", dir.join("go")), ("GOPATH", dir.join("go_path"))],
+        };
+        generate_scripts(dir, "go", &env).unwrap();
+        assert!(dir.join("go_path").is_dir());
+
+        let script = r#"export GOROOT=/pre/go; unset GOPATH
+source ./activiate.sh; echo "$GOROOT"
+source ./deactiviate.sh; echo "$GOROOT"; echo "${GOPATH-unset}""#;
+        let output = Command::new("bash").arg("-c").arg(script).current_dir(dir).output().unwrap();
+        let output = String::from_utf8_lossy(&output.stdout);
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(vec![dir.join("go").to_str().unwrap(), "/pre/go", "unset"], lines);
+    }
+}
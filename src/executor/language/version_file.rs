@@ -0,0 +1,164 @@
+use std::fs::{read_to_string, write};
+use std::io::Result;
+use std::path::{Path, PathBuf};
+
+/// version file of asdf, which contains versions of multiple tools.
+pub static TOOL_VERSIONS_FILE: &str = ".tool-versions";
+
+/// environment variable which pins the version of `lang` in a shell, e.g. `UVM_GO_VERSION`.
+pub fn version_env_name(lang: &str) -> String {
+    format!("UVM_{}_VERSION", lang.to_uppercase())
+}
+
+/// VersionFile is a file in a project which pins the version of a language.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionFile {
+    pub path: PathBuf,
+    pub version: String,
+}
+
+/// names of version files of a language, the first one is created by `local` command.
+fn version_file_names(lang: &str) -> &'static [&'static str] {
+    match lang {
+        "go" => &[".go-version"],
+        "node" => &[".nvmrc", ".node-version"],
+        "java" => &[".java-version"],
+        _ => &[],
+    }
+}
+
+/// name of a language in `.tool-versions`.
+fn tool_name(lang: &str) -> &str {
+    match lang {
+        "go" => "golang",
+        "node" => "nodejs",
+        _ => lang,
+    }
+}
+
+/// find the nearest version file of `lang` from `current_dir`,
+/// in the same folder, files of the language take precedence over `.tool-versions`.
+pub fn find_version_file(lang: &str, current_dir: &Path) -> Result<Option<VersionFile>> {
+    for dir in current_dir.ancestors() {
+        for name in version_file_names(lang) {
+            let path = dir.join(name);
+            if path.is_file() {
+                if let Some(version) = parse_version(lang, &read_to_string(&path)?) {
+                    return Ok(Some(VersionFile { path, version }));
+                }
+            }
+        }
+        let path = dir.join(TOOL_VERSIONS_FILE);
+        if path.is_file() {
+            if let Some(version) = parse_tool_versions(lang, &read_to_string(&path)?) {
+                return Ok(Some(VersionFile { path, version }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// write `version` to the version file of `lang` in `dir`,
+/// an existing version file in `dir` is updated, otherwise a new one is created.
+pub fn write_version_file(lang: &str, dir: &Path, version: &str) -> Result<PathBuf> {
+    let names = version_file_names(lang);
+    if let Some(path) = names.iter().map(|n| dir.join(n)).find(|p| p.is_file()) {
+        write(&path, format!("{}\n", version))?;
+        return Ok(path);
+    }
+    let path = dir.join(TOOL_VERSIONS_FILE);
+    if path.is_file() {
+        let content = read_to_string(&path)?;
+        if parse_tool_versions(lang, &content).is_some() {
+            write(&path, update_tool_versions(lang, &content, version))?;
+            return Ok(path);
+        }
+    }
+    let path = dir.join(names.first().unwrap_or(&TOOL_VERSIONS_FILE));
+    write(&path, format!("{}\n", version))?;
+    Ok(path)
+}
+
+/// get version from the first line which is not empty or comment.
+fn parse_version(lang: &str, content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .find(|l| !l.is_empty())
+        .map(|l| normalize_version(lang, l))
+}
+
+/// get version from lines like `nodejs 20.10.0`, only the first version is used.
+fn parse_tool_versions(lang: &str, content: &str) -> Option<String> {
+    let tool = tool_name(lang);
+    content.lines().find_map(|line| {
+        let mut parts = line.split('#').next().unwrap_or("").split_whitespace();
+        if parts.next() == Some(tool) {
+            parts.next().map(|v| normalize_version(lang, v))
+        } else {
+            None
+        }
+    })
+}
+
+fn update_tool_versions(lang: &str, content: &str, version: &str) -> String {
+    let tool = tool_name(lang);
+    let mut result = String::new();
+    for line in content.lines() {
+        if line.split_whitespace().next() == Some(tool) {
+            result.push_str(&format!("{} {}\n", tool, version));
+        } else {
+            result.push_str(line);
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// remove prefixes which are not part of installed version names, e.g. `v` of `v20.10.0`.
+fn normalize_version(lang: &str, version: &str) -> String {
+    let version = match lang {
+        "go" => version.strip_prefix("go").unwrap_or(version),
+        "node" => version.strip_prefix('v').unwrap_or(version),
+        _ => version,
+    };
+    version.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_version_file, parse_tool_versions, parse_version, write_version_file, VersionFile};
+    use crate::tool::test_dir::TestDir;
+    use std::fs;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(Some("20.10.0".to_string()), parse_version("node", "# comment\n\nv20.10.0\n"));
+        assert_eq!(Some("1.21.6".to_string()), parse_version("go", "go1.21.6"));
+        assert_eq!(None, parse_version("java", "\n"));
+        let content = "golang 1.21.6\nnodejs 20.10.0 18.19.0 # comment\n";
+        assert_eq!(Some("20.10.0".to_string()), parse_tool_versions("node", content));
+        assert_eq!(Some("1.21.6".to_string()), parse_tool_versions("go", content));
+        assert_eq!(None, parse_tool_versions("java", content));
+    }
+
+    #[test]
+    fn test_find_version_file() {
+        let test_dir = TestDir::new("find_version_file");
+        let root = test_dir.path();
+        let nested = root.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(".nvmrc"), "18\n").unwrap();
+        fs::write(nested.join(".tool-versions"), "golang 1.21.6\n").unwrap();
+
+        let node = find_version_file("node", &nested).unwrap();
+        assert_eq!(Some(VersionFile { path: root.join(".nvmrc"), version: "18".to_string() }), node);
+        let go = find_version_file("go", &nested).unwrap();
+        assert_eq!(Some("1.21.6".to_string()), go.map(|f| f.version));
+        assert_eq!(None, find_version_file("java", &nested).unwrap());
+
+        assert_eq!(nested.join(".tool-versions"), write_version_file("go", &nested, "1.22.0").unwrap());
+        assert_eq!(nested.join(".java-version"), write_version_file("java", &nested, "corretto-21").unwrap());
+        assert_eq!("golang 1.22.0\n", fs::read_to_string(nested.join(".tool-versions")).unwrap());
+    }
+}
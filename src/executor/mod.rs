@@ -12,7 +12,14 @@ pub use language::{
     GeneralLanguageContext,
     InstallResult,
     UseResult,
+    CurrentResult,
+    LanguageEnv,
+    LanguageError,
+    ListFilter,
+    OutdatedVersion,
+    UpgradeResult,
     VenvResult,
+    version_env_name,
 };
 
-pub use config::{ConfigExecutor, ConfigContext, ConfigKey, load_config};
\ No newline at end of file
+pub use config::{ConfigExecutor, ConfigContext, ConfigKey, GeneralLanguageConfig, UvmConfig, load_config, merge_aliases};
\ No newline at end of file
@@ -1,20 +1,30 @@
 use crate::{
     executor::{
-        load_config, ConfigContext, ConfigKey, ExecutorContext, GeneralLanguageContext,
-        GolangExecutor, JavaExecutor, JavaLanguageContext, LanguageExecutor, NodeExecutor,
+        load_config, merge_aliases, ConfigContext, ConfigKey, ExecutorContext, GeneralLanguageConfig,
+        GeneralLanguageContext, GolangExecutor, JavaExecutor, JavaLanguageContext, LanguageContext,
+        LanguageEnv, LanguageExecutor, ListFilter, NodeExecutor, OutdatedVersion, UvmConfig,
+        version_env_name,
     },
     renderer::{
         ConfigRenderer, ConfigTerminalRenderer, LanguageRenderer, LanguageTerminalRenderer,
     },
     tool::{
+        cache::{IndexCache, DEFAULT_TTL_MINUTES},
         args::{
-            ConfigCommand, ConfigSubCommand, GoCommand, JavaLanguageCommand, LanguageCommand,
-            TopCommand,
+            ConfigCommand, ConfigSubCommand, CurrentCommand, EnvCommand, ExecCommand, GoCommand, InitCommand,
+            JavaLanguageCommand, LanguageCommand, MigrateDataCommand, OutdatedCommand, ShellCommand,
+            TopCommand, WhichCommand,
         },
-        fs::AppDir,
+        fs::{get_uvm_home, normalize_path, AppDir},
+        http::resolve_proxy,
         logger::{debug, init_logger},
+        process,
+        shell::{get_env_changes, Shell, DIR_ENV, SHELL_COMMAND},
+        shim::SHIM_COMMAND,
+        GO, JAVA, NODE,
     },
 };
+use colored::Colorize;
 use log::error;
 use std::{
     collections::{HashMap, HashSet},
@@ -26,23 +36,79 @@ mod executor;
 mod renderer;
 mod tool;
 
+static LANGUAGES: [&str; 3] = ["go", "node", "java"];
+
 fn main() {
-    let app_dir = AppDir::new().unwrap();
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let (config, warnings) = load_config(&get_uvm_home().unwrap(), &current_dir);
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).is_some_and(|a| a == SHIM_COMMAND) {
+        // arguments of shims are passed to executables as they are, so they are not parsed by argh,
+        // shims run before every executable, so they neither create directories nor write logs
+        let code = match AppDir::open(config.data_dir.as_deref()) {
+            Ok(app_dir) => execute_shim(&args[2..], &app_dir, &config),
+            Err(err) => {
+                eprintln!("uvm: failed to locate data dir:\n\t{}", err.to_string().red());
+                1
+            }
+        };
+        std::process::exit(code);
+    }
+    let app_dir = match AppDir::new(config.data_dir.as_deref()) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("uvm: failed to prepare data dir:\n\t{}", err.to_string().red());
+            std::process::exit(1);
+        }
+    };
     init_logger(app_dir.get_log_dir()).unwrap();
     let commands: tool::args::UVMCommand = argh::from_env();
     debug!("args: {:?}", commands);
+    // the shell hook runs `env` before each prompt, warnings are shown by other commands
+    if !matches!(commands.top, TopCommand::Env(_)) {
+        for warning in warnings {
+            eprintln!("uvm: warning: {}", warning.yellow());
+        }
+    }
     match commands.top {
-        // TopCommand::Config(config_cmd) => {
-        //     execute_config(config_cmd, &app_dir);
-        // }
+        TopCommand::Config(config_cmd) => {
+            execute_config(config_cmd, &app_dir);
+        }
+        TopCommand::MigrateData(migrate_cmd) => {
+            execute_migrate_data(migrate_cmd, &app_dir);
+        }
+        TopCommand::Current(current_cmd) => {
+            execute_current(current_cmd, &app_dir, &config);
+        }
+        TopCommand::Reshim(_) => {
+            execute_reshim(&app_dir, &config);
+        }
+        TopCommand::Exec(exec_cmd) => {
+            std::process::exit(execute_exec(exec_cmd, &app_dir, &config));
+        }
+        TopCommand::Init(init_cmd) => {
+            execute_init(init_cmd);
+        }
+        TopCommand::Env(env_cmd) => {
+            execute_env(env_cmd, &app_dir, &config);
+        }
+        TopCommand::Shell(shell_cmd) => {
+            execute_shell(shell_cmd, &app_dir, &config);
+        }
+        TopCommand::Which(which_cmd) => {
+            std::process::exit(execute_which(which_cmd, &app_dir, &config));
+        }
+        TopCommand::Outdated(outdated_cmd) => {
+            execute_outdated(outdated_cmd, &app_dir, &config);
+        }
         TopCommand::Go(go_cmd) => {
-            execute_golang(go_cmd, &app_dir);
+            execute_golang(go_cmd, &app_dir, &config);
         }
         TopCommand::Node(node_cmd) => {
-            execute_node(node_cmd, &app_dir);
+            execute_node(node_cmd, &app_dir, &config);
         }
         TopCommand::Java(java_cmd) => {
-            execute_java(java_cmd, &app_dir);
+            execute_java(java_cmd, &app_dir, &config);
         }
         TopCommand::Update(_) => {
             execute_update();
@@ -92,83 +158,504 @@ fn execute_config(cmd: ConfigCommand, app_dir: &AppDir) {
     let terminal = ConfigTerminalRenderer::new(executor);
     let context = ConfigContext {
         home_dir: app_dir.get_home_dir().to_path_buf(),
+        current_dir: std::env::current_dir().unwrap_or_default(),
     };
     match cmd.command {
         ConfigSubCommand::Del(del) => {
-            let mut keys = HashSet::new();
-            if del.proxy {
-                keys.insert(ConfigKey::Proxy);
+            if let Some(keys) = parse_config_keys(&del.keys) {
+                terminal.del(keys, context);
             }
-            terminal.del(keys, context);
         }
         ConfigSubCommand::Get(get) => {
-            let mut keys = HashSet::new();
-            if get.proxy {
-                keys.insert(ConfigKey::Proxy);
+            if let Some(keys) = parse_config_keys(&get.keys) {
+                terminal.get(keys, context);
             }
-            terminal.get(keys, context);
         }
         ConfigSubCommand::Set(set) => {
-            let mut kvs = HashMap::new();
-            if let Some(proxy) = set.proxy {
-                kvs.insert(ConfigKey::Proxy, proxy);
+            if let Some(keys) = parse_config_keys(&[set.key]) {
+                let kvs: HashMap<_, _> = keys.into_iter().map(|k| (k, set.value.clone())).collect();
+                terminal.set(kvs, context);
             }
-            terminal.set(kvs, context);
+        }
+        ConfigSubCommand::List(list) => {
+            terminal.list(list.show_origin, context);
         }
     }
 }
 
-fn execute_java(cmd: JavaCommand, app_dir: &AppDir) {
-    let lang = JavaExecutor::new();
-    let lang_dir = app_dir
-        .get_language_dir(lang.name())
-        .expect("should have dir for go");
-    let terminal = LanguageTerminalRenderer::new(lang);
-    let config = load_config(app_dir.get_home_dir());
-    let mut context = ExecutorContext::<JavaLanguageContext> {
-        language_context: None,
-        proxy: config.proxy,
-        language_dir: lang_dir,
-        filter: Option::None,
-        arch: ARCH,
-        os: OS,
+/// convert option names to `ConfigKey`, print valid names if any of them is unknown.
+fn parse_config_keys(keys: &[String]) -> Option<HashSet<ConfigKey>> {
+    let mut result = HashSet::new();
+    for key in keys {
+        match ConfigKey::from_str(key) {
+            Some(k) => {
+                result.insert(k);
+            }
+            None => {
+                println!(
+                    "unknown configuration option `{}`, valid options include {}",
+                    key.red(),
+                    ConfigKey::all_names().join(", ")
+                );
+                return None;
+            }
+        }
+    }
+    Some(result)
+}
+
+fn execute_migrate_data(cmd: MigrateDataCommand, app_dir: &AppDir) {
+    let to_dir = match std::env::current_dir() {
+        Ok(dir) => normalize_path(&dir.join(&cmd.dir)),
+        Err(e) => {
+            error!("failed to get current dir: {}", e);
+            return;
+        }
+    };
+    // data is moved from the data dir of user configuration, projects never decide where it is
+    let app_dir = match UvmConfig::load_config(app_dir.get_home_dir())
+        .map_err(|e| e.to_string())
+        .and_then(|c| AppDir::new(c.data_dir.as_deref()).map_err(|e| e.to_string()))
+    {
+        Ok(dir) => dir,
+        Err(err) => {
+            error!("failed to load data dir: {}", err);
+            println!("failed to load data dir:\n\t{}", err.red());
+            return;
+        }
+    };
+    if let Err(err) = app_dir.migrate_data(&to_dir) {
+        error!("failed to migrate data: {}", err);
+        println!("failed to migrate data:\n\t{}", err.to_string().red());
+        return;
+    }
+    let executor = executor::ConfigExecutor {};
+    let context = ConfigContext {
+        home_dir: app_dir.get_home_dir().to_path_buf(),
+        current_dir: std::env::current_dir().unwrap_or_default(),
+    };
+    let mut kvs = HashMap::new();
+    kvs.insert(ConfigKey::DataDir, to_dir.to_string_lossy().to_string());
+    match executor.set(kvs, &context) {
+        Ok(_) => println!(
+            "migrated data from {} to {}.",
+            app_dir.get_data_dir().to_string_lossy(),
+            to_dir.to_string_lossy().green()
+        ),
+        Err(err) => {
+            error!("failed to save data dir: {}", err);
+            println!("failed to save data dir:\n\t{}", err.to_string().red());
+        }
+    }
+}
+
+fn execute_current(cmd: CurrentCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let languages = match cmd.language {
+        Some(lang) => {
+            if !LANGUAGES.contains(&lang.as_str()) {
+                println!("unknown language `{}`, valid languages include go, node, java", lang.red());
+                return;
+            }
+            vec![lang]
+        }
+        None => LANGUAGES.iter().map(|l| l.to_string()).collect(),
+    };
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    for lang in languages {
+        if lang == JAVA.name {
+            let context = java_context(app_dir, config);
+            LanguageTerminalRenderer::new(JavaExecutor::new()).current(&current_dir, &context);
+        } else if lang == NODE.name {
+            let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+            LanguageTerminalRenderer::new(NodeExecutor::new()).current(&current_dir, &context);
+        } else {
+            let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+            LanguageTerminalRenderer::new(GolangExecutor::new()).current(&current_dir, &context);
+        }
+    }
+}
+
+fn execute_reshim(app_dir: &AppDir, config: &UvmConfig) {
+    let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+    LanguageTerminalRenderer::new(GolangExecutor::new()).reshim(&context);
+    let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+    LanguageTerminalRenderer::new(NodeExecutor::new()).reshim(&context);
+    let context = java_context(app_dir, config);
+    LanguageTerminalRenderer::new(JavaExecutor::new()).reshim(&context);
+    println!(
+        "please add `{}` to your path to use shims.",
+        app_dir.get_shims_dir().to_string_lossy().green()
+    );
+}
+
+/// execute a shim with arguments like `go gofmt -l .`, returns the exit code.
+fn execute_shim(args: &[String], app_dir: &AppDir, config: &UvmConfig) -> i32 {
+    let (lang, name, args) = match args {
+        [lang, name, args @ ..] => (lang.as_str(), name.as_str(), args),
+        _ => {
+            eprintln!("usage: uvm {} <language> <executable> [args...]", SHIM_COMMAND);
+            return 1;
+        }
+    };
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let target = if lang == JAVA.name {
+        JavaExecutor::new().get_shim_target(name, &current_dir, &java_context(app_dir, config))
+    } else if lang == NODE.name {
+        let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+        NodeExecutor::new().get_shim_target(name, &current_dir, &context)
+    } else if lang == GO.name {
+        let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+        GolangExecutor::new().get_shim_target(name, &current_dir, &context)
+    } else {
+        eprintln!("unknown language `{}`, valid languages include go, node, java", lang);
+        return 1;
     };
-    execute_java_command(cmd.command, terminal, &mut context);
+    let result = target.map_err(|e| e.to_string()).and_then(|(bin, env)| {
+        process::exec(&bin, args, &env.paths, &env.vars).map_err(|e| e.to_string())
+    });
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            error!("failed to execute shim {}: {}", name, err);
+            eprintln!("uvm: failed to execute {}:\n\t{}", name, err.red());
+            1
+        }
+    }
+}
+
+/// execute a command with versions like `go@1.21.6`, returns the exit code.
+fn execute_exec(cmd: ExecCommand, app_dir: &AppDir, config: &UvmConfig) -> i32 {
+    let specs: Vec<_> = cmd
+        .args
+        .iter()
+        .map_while(|a| a.split_once('@').filter(|(lang, _)| LANGUAGES.contains(lang)))
+        .collect();
+    let command = &cmd.args[specs.len()..];
+    if specs.is_empty() || command.is_empty() {
+        println!("usage: uvm exec <language>@<version>... -- <command> [args...]");
+        return 1;
+    }
+    let mut env = LanguageEnv::default();
+    for (lang, version) in specs {
+        let result = if lang == JAVA.name {
+            let context = java_context(app_dir, config);
+            JavaExecutor::new().get_version_env(&context.resolve_alias(version.to_string()), &context)
+        } else if lang == NODE.name {
+            let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+            NodeExecutor::new().get_version_env(&context.resolve_alias(version.to_string()), &context)
+        } else {
+            let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+            GolangExecutor::new().get_version_env(&context.resolve_alias(version.to_string()), &context)
+        };
+        match result {
+            Ok((installed, lang_env)) => {
+                debug!("use {} {}: {:?}", lang, installed, lang_env);
+                env.merge(lang_env);
+            }
+            Err(err) => {
+                error!("failed to execute `exec` command:{}", err);
+                println!("failed to execute `exec` command:\n\t{} {}", lang, err.to_string().red());
+                return 1;
+            }
+        }
+    }
+    match process::exec(&command[0], &command[1..], &env.paths, &env.vars) {
+        Ok(code) => code,
+        Err(err) => {
+            error!("failed to execute `exec` command:{}", err);
+            println!("failed to execute `exec` command:\n\t{}", err.to_string().red());
+            1
+        }
+    }
+}
+
+fn parse_shell(shell: &str) -> Option<Shell> {
+    let result = Shell::from_str(shell);
+    if result.is_none() {
+        eprintln!("unknown shell `{}`, valid shells include bash, zsh, fish, pwsh", shell.red());
+    }
+    result
+}
+
+fn execute_init(cmd: InitCommand) {
+    let shell = match parse_shell(&cmd.shell) {
+        Some(s) => s,
+        None => return,
+    };
+    match std::env::current_exe() {
+        Ok(uvm_exe) => print!("{}", shell.hook(&uvm_exe)),
+        Err(err) => {
+            error!("failed to execute `init` command:{}", err);
+            eprintln!("failed to execute `init` command:\n\t{}", err.to_string().red());
+        }
+    }
 }
 
-fn execute_golang(cmd: GoCommand, app_dir: &AppDir) {
-    let lang = GolangExecutor::new();
-    let lang_dir = app_dir
-        .get_language_dir(lang.name())
-        .expect("should have dir for go");
-    let terminal = LanguageTerminalRenderer::new(lang);
-    let config = load_config(app_dir.get_home_dir());
-    let mut context = ExecutorContext::<GeneralLanguageContext> {
+/// print statements which apply environment variables of versions used in current dir,
+/// errors are only shown once after the directory changes, since the hook runs on every prompt.
+fn execute_env(cmd: EnvCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let shell = match parse_shell(&cmd.shell) {
+        Some(s) => s,
+        None => return,
+    };
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let dir_changed = std::env::var_os(DIR_ENV).is_none_or(|d| d != current_dir.as_os_str());
+    let mut env = LanguageEnv::default();
+    for lang in LANGUAGES {
+        let result = if lang == JAVA.name {
+            JavaExecutor::new().get_used_env(&current_dir, &java_context(app_dir, config))
+        } else if lang == NODE.name {
+            let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+            NodeExecutor::new().get_used_env(&current_dir, &context)
+        } else {
+            let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+            GolangExecutor::new().get_used_env(&current_dir, &context)
+        };
+        match result {
+            Ok(Some(lang_env)) => env.merge(lang_env),
+            Ok(None) => {}
+            Err(err) => {
+                error!("failed to get environment of {}:{}", lang, err);
+                if dir_changed {
+                    eprintln!("uvm: {} {}", lang, err.to_string().yellow());
+                }
+            }
+        }
+    }
+    let changes = get_env_changes(|n| std::env::var(n).ok(), &current_dir, &env.paths, &env.vars);
+    print!("{}", shell.format_changes(&changes));
+}
+
+/// print statements which set or unset `UVM_<LANG>_VERSION`, they are evaluated by the `uvm` function of the hook,
+/// so messages are printed to stderr.
+fn execute_shell(cmd: ShellCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let shell = Shell::from_env().unwrap_or_else(|| {
+        let shell = Shell::default_shell();
+        eprintln!(
+            "shell integration is not enabled, please add `{}` to your shell rc file, or evaluate the output manually.",
+            format!("eval \"$(uvm init {})\"", shell.name()).green()
+        );
+        shell
+    });
+    let language = cmd.args.first().cloned();
+    if let Some(lang) = language.as_deref().filter(|l| !LANGUAGES.contains(l)) {
+        eprintln!("unknown language `{}`, valid languages include go, node, java", lang.red());
+        return;
+    }
+    let mut changes = vec![];
+    if cmd.unset {
+        for lang in LANGUAGES {
+            if language.as_deref().is_none_or(|l| l == lang) {
+                changes.push((version_env_name(lang), None));
+            }
+        }
+    } else {
+        let (lang, version) = match cmd.args.as_slice() {
+            [lang, version] => (lang.clone(), version.clone()),
+            _ => {
+                eprintln!("usage: uvm {} <language> <version>, or uvm {} [language] --unset", SHELL_COMMAND, SHELL_COMMAND);
+                return;
+            }
+        };
+        let result = if lang == JAVA.name {
+            let context = java_context(app_dir, config);
+            JavaExecutor::new().match_installed_version(context.resolve_alias(version.clone()), &context)
+        } else if lang == NODE.name {
+            let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+            NodeExecutor::new().match_installed_version(context.resolve_alias(version.clone()), &context)
+        } else {
+            let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+            GolangExecutor::new().match_installed_version(context.resolve_alias(version.clone()), &context)
+        };
+        match result {
+            Ok(Some(installed)) => {
+                eprintln!("{} {} is used in current shell.", lang, installed.green());
+                changes.push((version_env_name(&lang), Some(installed)));
+            }
+            Ok(None) => {
+                eprintln!("failed to execute `shell` command:\n\t{}", format!("{} {} is not installed", lang, version).red());
+                return;
+            }
+            Err(err) => {
+                error!("failed to execute `shell` command:{}", err);
+                eprintln!("failed to execute `shell` command:\n\t{}", err.to_string().red());
+                return;
+            }
+        }
+    }
+    print!("{}", shell.format_changes(&changes));
+}
+
+/// print the path of an executable of the version used in current dir, returns the exit code.
+fn execute_which(cmd: WhichCommand, app_dir: &AppDir, config: &UvmConfig) -> i32 {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    for lang in LANGUAGES {
+        let result = if lang == JAVA.name {
+            JavaExecutor::new().which(&cmd.name, &current_dir, &java_context(app_dir, config))
+        } else if lang == NODE.name {
+            let context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
+            NodeExecutor::new().which(&cmd.name, &current_dir, &context)
+        } else {
+            let context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+            GolangExecutor::new().which(&cmd.name, &current_dir, &context)
+        };
+        match result {
+            Ok(Some(bin)) => {
+                println!("{}", bin.to_string_lossy());
+                return 0;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                error!("failed to execute `which` command:{}", err);
+                println!("failed to execute `which` command:\n\t{}", err.to_string().red());
+                return 1;
+            }
+        }
+    }
+    println!(
+        "failed to execute `which` command:\n\t{}",
+        format!("`{}` is not provided by any installed version", cmd.name).red()
+    );
+    1
+}
+
+/// print installed versions which have newer patch releases as a table.
+fn execute_outdated(cmd: OutdatedCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let languages = match cmd.language {
+        Some(lang) => {
+            if !LANGUAGES.contains(&lang.as_str()) {
+                println!("unknown language `{}`, valid languages include go, node, java", lang.red());
+                return;
+            }
+            vec![lang]
+        }
+        None => LANGUAGES.iter().map(|l| l.to_string()).collect(),
+    };
+    let mut rows: Vec<(String, OutdatedVersion)> = Vec::new();
+    for lang in languages {
+        let result = if lang == JAVA.name {
+            let mut context = java_context(app_dir, config);
+            context.index_cache.set_refresh(cmd.refresh);
+            if cmd.offline {
+                context.set_offline(true);
+            }
+            JavaExecutor::new().outdated(&context)
+        } else {
+            let language_config = if lang == NODE.name { config.node.as_ref() } else { config.go.as_ref() };
+            let mut context = general_context(app_dir, &lang, language_config, config);
+            context.index_cache.set_refresh(cmd.refresh);
+            if cmd.offline {
+                context.set_offline(true);
+            }
+            if lang == NODE.name {
+                NodeExecutor::new().outdated(&context)
+            } else {
+                GolangExecutor::new().outdated(&context)
+            }
+        };
+        match result {
+            Ok(versions) => rows.extend(versions.into_iter().map(|v| (lang.clone(), v))),
+            Err(err) => {
+                error!("failed to execute `outdated` command:{}", err);
+                println!("failed to execute `outdated` command for {}:\n\t{}", lang, err.to_string().red());
+            }
+        }
+    }
+    if rows.is_empty() {
+        println!("all installed versions are up to date.");
+        return;
+    }
+    let width = rows.iter().map(|(_, v)| v.installed.len()).max().unwrap_or_default().max("installed".len());
+    println!("  {:<10}{:<width$}  latest", "language", "installed");
+    for (lang, version) in rows {
+        let mark = if version.inuse { "*".green() } else { " ".normal() };
+        println!("{} {:<10}{:<width$}  {}", mark, lang, version.installed, version.latest.green());
+    }
+}
+
+fn index_cache(app_dir: &AppDir, name: &str, config: &UvmConfig) -> IndexCache {
+    let language_dir = app_dir
+        .get_language_dir(name)
+        .expect("should have dir for language");
+    let mut cache = IndexCache::new(language_dir.get_cache_dir(), config.index_ttl.unwrap_or(DEFAULT_TTL_MINUTES));
+    cache.set_offline(config.offline.unwrap_or(false));
+    cache
+}
+
+fn java_context<'a>(app_dir: &'a AppDir, config: &UvmConfig) -> ExecutorContext<'a, JavaLanguageContext> {
+    ExecutorContext::<JavaLanguageContext> {
         language_context: None,
-        proxy: config.proxy,
-        language_dir: lang_dir,
-        filter: Option::None,
+        proxy: resolve_proxy(&[
+            config.java.as_ref().and_then(|c| c.proxy.as_ref()),
+            config.proxy.as_ref(),
+        ]),
+        mirror: config.java.as_ref().and_then(|c| c.mirror.clone()),
+        language_dir: app_dir
+            .get_language_dir(JAVA.name)
+            .expect("should have dir for java"),
+        shims_dir: app_dir.get_shims_dir(),
+        filter: ListFilter {
+            installable: config.installable_only.unwrap_or(false),
+            ..ListFilter::default()
+        },
+        aliases: merge_aliases(config.aliases.clone(), config.java.as_ref().and_then(|c| c.aliases.clone()))
+            .unwrap_or_default(),
+        index_cache: index_cache(app_dir, JAVA.name, config),
+        offline: config.offline.unwrap_or(false),
+        keep_archives: config.keep_archives.unwrap_or(false),
         arch: ARCH,
         os: OS,
-    };
-    execute_command(cmd.command, terminal, &mut context);
+    }
 }
 
-fn execute_node(cmd: NodeCommand, app_dir: &AppDir) {
-    let lang = NodeExecutor::new();
-    let lang_dir = app_dir
-        .get_language_dir(lang.name())
-        .expect("should have dir for node");
-    let terminal = LanguageTerminalRenderer::new(lang);
-    let config = load_config(app_dir.get_home_dir());
-    let mut context = ExecutorContext::<GeneralLanguageContext> {
+/// context of a language which uses `GeneralLanguageConfig`, e.g. go and node.
+fn general_context<'a>(
+    app_dir: &'a AppDir,
+    name: &str,
+    lang_config: Option<&GeneralLanguageConfig>,
+    config: &UvmConfig,
+) -> ExecutorContext<'a, GeneralLanguageContext> {
+    ExecutorContext::<GeneralLanguageContext> {
         language_context: None,
-        proxy: config.proxy,
-        language_dir: lang_dir,
-        filter: Option::None,
+        proxy: resolve_proxy(&[
+            lang_config.and_then(|c| c.proxy.as_ref()),
+            config.proxy.as_ref(),
+        ]),
+        mirror: lang_config.and_then(|c| c.mirror.clone()),
+        language_dir: app_dir
+            .get_language_dir(name)
+            .expect("should have dir for language"),
+        shims_dir: app_dir.get_shims_dir(),
+        filter: ListFilter {
+            installable: config.installable_only.unwrap_or(false),
+            ..ListFilter::default()
+        },
+        aliases: merge_aliases(config.aliases.clone(), lang_config.and_then(|c| c.aliases.clone()))
+            .unwrap_or_default(),
+        index_cache: index_cache(app_dir, name, config),
+        offline: config.offline.unwrap_or(false),
+        keep_archives: config.keep_archives.unwrap_or(false),
         arch: ARCH,
         os: OS,
-    };
+    }
+}
+
+fn execute_java(cmd: JavaCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let terminal = LanguageTerminalRenderer::new(JavaExecutor::new());
+    let mut context = java_context(app_dir, config);
+    let default_vendor = config.java.as_ref().and_then(|c| c.default_vendor.clone());
+    execute_java_command(cmd.command, default_vendor, terminal, &mut context);
+}
+
+fn execute_golang(cmd: GoCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let terminal = LanguageTerminalRenderer::new(GolangExecutor::new());
+    let mut context = general_context(app_dir, GO.name, config.go.as_ref(), config);
+    execute_command(cmd.command, terminal, &mut context);
+}
+
+fn execute_node(cmd: NodeCommand, app_dir: &AppDir, config: &UvmConfig) {
+    let terminal = LanguageTerminalRenderer::new(NodeExecutor::new());
+    let mut context = general_context(app_dir, NODE.name, config.node.as_ref(), config);
     execute_command(cmd.command, terminal, &mut context);
 }
 
@@ -179,89 +666,273 @@ fn execute_command<'a, T: LanguageExecutor<'a, GeneralLanguageContext>>(
 ) {
     match cmd {
         LanguageCommand::List(list) => {
+            if list.mirror.is_some() {
+                context.mirror = list.mirror;
+            }
+            context.index_cache.set_refresh(list.refresh);
+            if list.offline {
+                context.set_offline(true);
+            }
+            if list.proxy.is_some() {
+                context.proxy = list.proxy;
+            }
+            let stable = match (list.stable, list.unstable) {
+                (true, true) => {
+                    println!("failed to execute `list` command:\n\t{}", "`--stable` and `--unstable` cannot be used together".red());
+                    return;
+                }
+                (true, false) => Some(true),
+                (false, true) => Some(false),
+                (false, false) => None,
+            };
+            context.filter = ListFilter {
+                text: list.filter,
+                major: list.major,
+                lts: list.lts,
+                stable,
+                latest_per_minor: list.latest_per_minor,
+                installable: (list.installable || context.filter.installable) && !list.all_platforms,
+            };
             terminal.list(list.local, context);
         }
         LanguageCommand::Install(install) => {
+            if install.mirror.is_some() {
+                context.mirror = install.mirror;
+            }
+            context.index_cache.set_refresh(install.refresh);
+            if install.offline {
+                context.set_offline(true);
+            }
+            if install.proxy.is_some() {
+                context.proxy = install.proxy;
+            }
             context.merge(Some(GeneralLanguageContext {
                 no_use: install.no_use,
+                lts: install.lts,
+                prerelease: install.pre,
             }));
-            terminal.install(install.version, context);
+            if let Some(version) =
+                get_version(install.version, install.from_project, false, &terminal, context)
+            {
+                terminal.install(version, context);
+            }
         }
         LanguageCommand::Use(use_cmd) => {
-            terminal.select(use_cmd.version, context);
+            let from_project = use_cmd.version.is_none();
+            if let Some(version) = get_version(use_cmd.version, from_project, true, &terminal, context) {
+                terminal.select(version, context);
+            }
         }
         LanguageCommand::Unuse(_) => {
             terminal.unuse(context);
         }
         LanguageCommand::Uninstall(uninstall) => {
-            terminal.uninstall(uninstall.version, context);
+            terminal.uninstall(context.resolve_alias(uninstall.version), uninstall.all, context);
         }
         LanguageCommand::VirtualEnv(venv) => {
-            terminal.venv(venv.version, venv.dir, context);
+            if let Some(version) = get_version(venv.version, venv.from_project, true, &terminal, context) {
+                terminal.venv(version, venv.dir, context);
+            }
+        }
+        LanguageCommand::Local(local) => {
+            terminal.local(context.resolve_alias(local.version), &std::env::current_dir().unwrap_or_default(), context);
+        }
+        LanguageCommand::Home(home) => {
+            terminal.home(home.version.map(|v| context.resolve_alias(v)), &std::env::current_dir().unwrap_or_default(), context);
+        }
+        LanguageCommand::Info(info) => {
+            if info.mirror.is_some() {
+                context.mirror = info.mirror;
+            }
+            context.index_cache.set_refresh(info.refresh);
+            if info.offline {
+                context.set_offline(true);
+            }
+            if info.proxy.is_some() {
+                context.proxy = info.proxy;
+            }
+            terminal.info(context.resolve_alias(info.version), context);
+        }
+        LanguageCommand::Upgrade(upgrade) => {
+            if upgrade.mirror.is_some() {
+                context.mirror = upgrade.mirror;
+            }
+            context.index_cache.set_refresh(upgrade.refresh);
+            if upgrade.offline {
+                context.set_offline(true);
+            }
+            if upgrade.proxy.is_some() {
+                context.proxy = upgrade.proxy;
+            }
+            // the used version is moved by `upgrade` only if it's the upgraded one
+            context.merge(Some(GeneralLanguageContext {
+                no_use: true,
+                lts: false,
+                prerelease: false,
+            }));
+            terminal.upgrade(upgrade.version.map(|v| context.resolve_alias(v)), upgrade.prune, context);
+        }
+    }
+}
+
+/// get version given by `-v` with user-defined aliases resolved,
+/// or version required by the project in current dir if `from_project` is set.
+fn get_version<'a, L: LanguageContext, R: LanguageRenderer<'a, L>>(
+    version: Option<String>,
+    from_project: bool,
+    installed_only: bool,
+    terminal: &R,
+    context: &ExecutorContext<'a, L>,
+) -> Option<String> {
+    match (version, from_project) {
+        (Some(v), false) => Some(context.resolve_alias(v)),
+        (None, true) => {
+            let current_dir = std::env::current_dir().unwrap_or_default();
+            terminal.project_version(installed_only, &current_dir, context)
+        }
+        (Some(_), true) => {
+            println!("`-v` and `--from-project` cannot be used together.");
+            None
+        }
+        (None, false) => {
+            println!("please specify a version by `-v`, or use `--from-project`.");
+            None
         }
     }
 }
 
 fn execute_java_command<'a, T: LanguageExecutor<'a, JavaLanguageContext>>(
     cmd: JavaLanguageCommand,
+    default_vendor: Option<String>,
     terminal: LanguageTerminalRenderer<T>,
     context: &'a mut ExecutorContext<'a, JavaLanguageContext>,
 ) {
     match cmd {
         JavaLanguageCommand::List(list) => {
-            if let Some(v) = list.vendor {
-                context.merge(Some(JavaLanguageContext {
-                    vendor: v,
-                    no_use: true,
-                }));
+            context.index_cache.set_refresh(list.refresh);
+            if list.offline {
+                context.set_offline(true);
+            }
+            if list.proxy.is_some() {
+                context.proxy = list.proxy;
             }
+            context.merge(Some(JavaLanguageContext {
+                vendor: list.vendor,
+                default_vendor,
+                no_use: true,
+            }));
+            context.filter = ListFilter {
+                text: list.filter,
+                major: list.major,
+                latest_per_minor: list.latest_per_minor,
+                installable: (list.installable || context.filter.installable) && !list.all_platforms,
+                ..ListFilter::default()
+            };
             terminal.list(list.local, context);
         }
         JavaLanguageCommand::Install(install) => {
+            if install.mirror.is_some() {
+                context.mirror = install.mirror.clone();
+            }
+            context.index_cache.set_refresh(install.refresh);
+            if install.offline {
+                context.set_offline(true);
+            }
+            if install.proxy.is_some() {
+                context.proxy = install.proxy.clone();
+            }
             context.merge(Some(JavaLanguageContext {
-                vendor: install.vendor.clone(),
+                vendor: install.vendor,
+                default_vendor,
                 no_use: install.no_use,
             }));
-            terminal.install(install.version, context);
+            if let Some(version) =
+                get_version(install.version, install.from_project, false, &terminal, context)
+            {
+                terminal.install(version, context);
+            }
         }
         JavaLanguageCommand::Use(use_cmd) => {
+            let from_project = use_cmd.version.is_none();
             context.merge(Some(JavaLanguageContext {
-                vendor: use_cmd.vendor.clone(),
+                vendor: use_cmd.vendor,
+                default_vendor: if from_project { default_vendor } else { None },
                 no_use: true,
             }));
-            let version = if use_cmd.version.starts_with(&use_cmd.vendor) {
-                use_cmd.version.clone()
-            } else {
-                format!("{}-{}", use_cmd.vendor, use_cmd.version)
-            };
-            terminal.select(version, context);
+            if let Some(version) = get_version(use_cmd.version, from_project, true, &terminal, context) {
+                terminal.select(version, context);
+            }
         }
         JavaLanguageCommand::Unuse(_) => {
             terminal.unuse(context);
         }
         JavaLanguageCommand::Uninstall(uninstall) => {
             context.merge(Some(JavaLanguageContext {
-                vendor: uninstall.vendor.clone(),
+                vendor: uninstall.vendor,
+                default_vendor: None,
                 no_use: true,
             }));
-            let version = if uninstall.version.starts_with(&uninstall.vendor) {
-                uninstall.version.clone()
-            } else {
-                format!("{}-{}", uninstall.vendor, uninstall.version)
-            };
-            terminal.uninstall(version, context);
+            terminal.uninstall(context.resolve_alias(uninstall.version), uninstall.all, context);
         }
         JavaLanguageCommand::VirtualEnv(venv) => {
             context.merge(Some(JavaLanguageContext {
-                vendor: venv.vendor.clone(),
+                vendor: venv.vendor,
+                default_vendor: if venv.from_project { default_vendor } else { None },
                 no_use: true,
             }));
-            let version = if venv.version.starts_with(&venv.vendor) {
-                venv.version.clone()
-            } else {
-                format!("{}-{}", venv.vendor, venv.version)
-            };
-            terminal.venv(version, venv.dir, context);
+            if let Some(version) = get_version(venv.version, venv.from_project, true, &terminal, context) {
+                terminal.venv(version, venv.dir, context);
+            }
+        }
+        JavaLanguageCommand::Local(local) => {
+            context.merge(Some(JavaLanguageContext {
+                vendor: local.vendor,
+                default_vendor: None,
+                no_use: true,
+            }));
+            terminal.local(context.resolve_alias(local.version), &std::env::current_dir().unwrap_or_default(), context);
+        }
+        JavaLanguageCommand::Home(home) => {
+            context.merge(Some(JavaLanguageContext {
+                vendor: home.vendor,
+                default_vendor: None,
+                no_use: true,
+            }));
+            terminal.home(home.version.map(|v| context.resolve_alias(v)), &std::env::current_dir().unwrap_or_default(), context);
+        }
+        JavaLanguageCommand::Info(info) => {
+            context.index_cache.set_refresh(info.refresh);
+            if info.offline {
+                context.set_offline(true);
+            }
+            if info.proxy.is_some() {
+                context.proxy = info.proxy;
+            }
+            context.merge(Some(JavaLanguageContext {
+                vendor: info.vendor,
+                default_vendor,
+                no_use: true,
+            }));
+            terminal.info(context.resolve_alias(info.version), context);
+        }
+        JavaLanguageCommand::Upgrade(upgrade) => {
+            if upgrade.mirror.is_some() {
+                context.mirror = upgrade.mirror;
+            }
+            context.index_cache.set_refresh(upgrade.refresh);
+            if upgrade.offline {
+                context.set_offline(true);
+            }
+            if upgrade.proxy.is_some() {
+                context.proxy = upgrade.proxy;
+            }
+            // the used version is moved by `upgrade` only if it's the upgraded one
+            context.merge(Some(JavaLanguageContext {
+                vendor: upgrade.vendor,
+                default_vendor: None,
+                no_use: true,
+            }));
+            terminal.upgrade(upgrade.version.map(|v| context.resolve_alias(v)), upgrade.prune, context);
         }
     }
 }
@@ -14,23 +14,43 @@ impl ConfigTerminalRenderer {
     pub fn new(executor: ConfigExecutor) -> Self {
         ConfigTerminalRenderer{ executor }
     }
+
+    fn print_options(&self, kvs: HashMap<ConfigKey, String>) {
+        let mut kvs: Vec<_> = kvs.into_iter().collect();
+        kvs.sort();
+        for (k, v) in kvs {
+            self.print_line(format!("{}: {}", k.name(), v));
+        }
+    }
 }
 
 impl ConfigRenderer for ConfigTerminalRenderer {
     fn get(&self, keys: HashSet<ConfigKey>, context: ConfigContext) {
         match self.executor.get(keys, &context) {
+            Ok(kvs) => self.print_options(kvs),
+            Err(err) => {
+                error!("failed to execute `get` command:{}", err);
+                self.print_line(format!("failed to execute `get` command:\n\t{}", err.to_string().red()));
+            },
+        }
+    }
+
+    fn list(&self, show_origin: bool, context: ConfigContext) {
+        match self.executor.list(&context) {
             Ok(kvs) => {
-                for (k, v) in kvs {
-                    match k {
-                        ConfigKey::Proxy => {
-                            self.print_line(format!("proxy: {}", v));
-                        }
+                let mut kvs: Vec<_> = kvs.into_iter().collect();
+                kvs.sort();
+                for (k, (v, origin)) in kvs {
+                    if show_origin {
+                        self.print_line(format!("{}\t{}: {}", origin.to_string_lossy(), k.name(), v));
+                    } else {
+                        self.print_line(format!("{}: {}", k.name(), v));
                     }
                 }
             },
             Err(err) => {
-                error!("failed to execute `get` command:{}", err);
-                self.print_line(format!("failed to execute `get` command:\n\t{}", err.to_string().red()));
+                error!("failed to execute `list` command:{}", err);
+                self.print_line(format!("failed to execute `list` command:\n\t{}", err.to_string().red()));
             },
         }
     }
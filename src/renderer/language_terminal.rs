@@ -1,8 +1,9 @@
 use colored::Colorize;
-use crate::executor::{LanguageExecutor, LanguageContext, ExecutorContext, InstallResult, VenvResult, UseResult};
+use crate::executor::{LanguageExecutor, LanguageContext, ExecutorContext, InstallResult, VenvResult, UseResult, CurrentResult, LanguageError, UpgradeResult};
 use crate::tool::logger::error;
 use super::uvm_renderer::{UvmRenderer, LanguageRenderer};
 use super::terminal::TerminalRenderer;
+use std::path::Path;
 
 /// LanguageTerminalRenderer executes `LanguageExecutor` and renders output on terminal.
 pub struct LanguageTerminalRenderer<T> {
@@ -17,6 +18,36 @@ impl<T> LanguageTerminalRenderer<T> {
     }
 }
 
+impl<T> LanguageTerminalRenderer<T> {
+    fn regenerate_shims<'a, L: LanguageContext>(&self, context: &ExecutorContext<'a, L>)
+    where
+        T: LanguageExecutor<'a, L>,
+    {
+        if let Err(err) = self.lang.reshim(context) {
+            error!("failed to regenerate shims:{}", err);
+            self.print_line(format!("failed to regenerate shims:\n\t{}", err.to_string().red()));
+        }
+    }
+
+    /// warn if `version` doesn't satisfy the version required by the project in current dir.
+    fn warn_project_version<'a, L: LanguageContext>(&self, version: &str)
+    where
+        T: LanguageExecutor<'a, L>,
+    {
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        match self.lang.check_project_version(version, &current_dir) {
+            Ok(Some(project)) => {
+                self.print_line(format!("warning: {} {} does not satisfy `{}` required by {}",
+                    self.lang.name(), version, project.requirement(), project.path.to_string_lossy()).yellow());
+            },
+            Ok(None) => {},
+            Err(err) => {
+                error!("failed to check project version:{}", err);
+            }
+        }
+    }
+}
+
 impl<T> TerminalRenderer for LanguageTerminalRenderer<T> {}
 
 impl<T> UvmRenderer for LanguageTerminalRenderer<T> {}
@@ -58,6 +89,7 @@ impl<'a, L:LanguageContext, T: LanguageExecutor<'a, L>> LanguageRenderer<'a, L>
                         self.print_line(format!("version {} is already install.", version));
                     },
                 }
+                self.regenerate_shims(context);
             },
             Err(err) => {
                 error!("failed to execute `install` command:{}", err);
@@ -66,16 +98,69 @@ impl<'a, L:LanguageContext, T: LanguageExecutor<'a, L>> LanguageRenderer<'a, L>
         }
     }
 
-    fn uninstall(&self, version: String, context: &'a ExecutorContext<'a, L>) {
-        match self.lang.uninstall(version.clone(), context) {
-            Ok(_) => {
-                self.print_line(format!("uninstalled version {} successfully.", version.green()));
+    fn info(&self, version: String, context: &'a ExecutorContext<'a, L>) {
+        match self.lang.info(version, context) {
+            Ok(info) => {
+                self.print_line(format!("{} {}", self.lang.name(), info.name.green()));
+                for (key, value) in info.details {
+                    self.print_line(format!("  {}: {}", key, value));
+                }
+                match info.installed_dir {
+                    Some(dir) => self.print_line(format!("  installed: {}", dir.to_string_lossy().green())),
+                    None => self.print_line("  installed: no"),
+                }
+                self.print_line("  packages:");
+                let width = info.packages.iter().map(|p| p.platform.len()).max().unwrap_or_default();
+                let kind_width = info.packages.iter().map(|p| p.kind.len()).max().unwrap_or_default();
+                for package in info.packages {
+                    let mut line = format!("    {:<width$}  {:<kind_width$}", package.platform, package.kind);
+                    for detail in package.details {
+                        line.push_str("  ");
+                        line.push_str(&detail);
+                    }
+                    self.print_line(line);
+                }
             },
+            Err(err) => {
+                error!("failed to execute `info` command:{}", err);
+                self.print_line(format!("failed to execute `info` command:\n\t{}", err.to_string().red()));
+            }
+        }
+    }
+
+    fn uninstall(&self, version: String, all: bool, context: &'a ExecutorContext<'a, L>) {
+        let names: Vec<String> = match self.lang.match_installed_versions(version.clone(), context) {
+            Ok(matched) => matched.into_iter().map(|v| v.name).collect(),
             Err(err) => {
                 error!("failed to execute `uninstall` command:{}", err);
                 self.print_line(format!("failed to execute `uninstall` command:\n\t{}", err.to_string().red()));
+                return;
+            }
+        };
+        if names.is_empty() {
+            self.print_line(format!("failed to execute `uninstall` command:\n\t{}",
+                LanguageError::VersionNotInstalled(version).to_string().red()));
+            return;
+        }
+        if names.len() > 1 && !all {
+            self.print_line(format!("{} matches {} installed versions: {}", version, names.len(), names.join(", ")));
+            if !self.confirm("uninstall all of them?") {
+                self.print_line(format!("nothing is uninstalled, use `{}` to uninstall all of them or specify one of them.", "--all".green()));
+                return;
+            }
+        }
+        for name in names {
+            match self.lang.uninstall(name.clone(), context) {
+                Ok(_) => {
+                    self.print_line(format!("uninstalled version {} successfully.", name.green()));
+                },
+                Err(err) => {
+                    error!("failed to execute `uninstall` command:{}", err);
+                    self.print_line(format!("failed to execute `uninstall` command:\n\t{}", err.to_string().red()));
+                }
             }
         }
+        self.regenerate_shims(context);
     }
 
     fn unuse(&self, context: &'a ExecutorContext<'a, L>) {
@@ -91,6 +176,14 @@ impl<'a, L:LanguageContext, T: LanguageExecutor<'a, L>> LanguageRenderer<'a, L>
     }
 
     fn select(&self, version: String, context: &'a ExecutorContext<'a, L>) {
+        let version = match self.lang.resolve_installed(version, context) {
+            Ok(v) => v,
+            Err(err) => {
+                error!("failed to execute `use` command:{}", err);
+                self.print_line(format!("failed to execute `use` command:\n\t{}", err.to_string().red()));
+                return;
+            }
+        };
         match self.lang.select(version.clone(), context) {
             Ok(r) => {
                 match r {
@@ -105,6 +198,7 @@ impl<'a, L:LanguageContext, T: LanguageExecutor<'a, L>> LanguageRenderer<'a, L>
                         self.print_line(format!("current used version is already {}.", version.green()));
                     },
                 }
+                self.warn_project_version(&version);
             },
             Err(err) => {
                 error!("failed to execute `use` command:{}", err);
@@ -114,6 +208,14 @@ impl<'a, L:LanguageContext, T: LanguageExecutor<'a, L>> LanguageRenderer<'a, L>
     }
 
     fn venv(&self, version: String, dir_name: String, context: &'a ExecutorContext<'a, L>) {
+        let version = match self.lang.resolve_installed(version, context) {
+            Ok(v) => v,
+            Err(err) => {
+                error!("failed to execute `venv` command:{}", err);
+                self.print_line(format!("failed to execute `venv` command:\n\t{}", err.to_string().red()));
+                return;
+            }
+        };
         match self.lang.venv(version.clone(), dir_name.clone(), context) {
             Ok(r) => {
                 match r {
@@ -138,4 +240,112 @@ impl<'a, L:LanguageContext, T: LanguageExecutor<'a, L>> LanguageRenderer<'a, L>
             }
         }
     }
+    fn current(&self, current_dir: &Path, context: &'a ExecutorContext<'a, L>) {
+        let name = self.lang.name();
+        match self.lang.current(current_dir, context) {
+            Ok(r) => {
+                let used_version = match &r {
+                    CurrentResult::Env(_, _, v) | CurrentResult::Local(_, v) | CurrentResult::Global(v) => v.clone(),
+                };
+                match r {
+                    CurrentResult::Env(env_name, _, Some(version)) => {
+                        self.print_line(format!("{} {} (set by {})", name, version.green(), env_name));
+                    },
+                    CurrentResult::Env(env_name, version, None) => {
+                        self.print_line(format!("{} {} (set by {}) is not installed, install it by `uvm {} install -v {}`",
+                            name, version.red(), env_name, name, version));
+                    },
+                    CurrentResult::Local(file, Some(version)) => {
+                        self.print_line(format!("{} {} (set by {})", name, version.green(), file.path.to_string_lossy()));
+                    },
+                    CurrentResult::Local(file, None) => {
+                        self.print_line(format!("{} {} (set by {}) is not installed, install it by `uvm {} install -v {}`",
+                            name, file.version.red(), file.path.to_string_lossy(), name, file.version));
+                    },
+                    CurrentResult::Global(Some(version)) => {
+                        self.print_line(format!("{} {} (global)", name, version.green()));
+                    },
+                    CurrentResult::Global(None) => {
+                        self.print_line(format!("{} is not used", name));
+                    },
+                }
+                if let Some(version) = used_version {
+                    self.warn_project_version(&version);
+                }
+            },
+            Err(err) => {
+                error!("failed to execute `current` command:{}", err);
+                self.print_line(format!("failed to execute `current` command:\n\t{}", err.to_string().red()));
+            }
+        }
+    }
+
+    fn local(&self, version: String, current_dir: &Path, context: &'a ExecutorContext<'a, L>) {
+        match self.lang.local(version.clone(), current_dir, context) {
+            Ok(path) => {
+                self.print_line(format!("pin version {} in {} successfully.", version.green(), path.to_string_lossy()));
+            },
+            Err(err) => {
+                error!("failed to execute `local` command:{}", err);
+                self.print_line(format!("failed to execute `local` command:\n\t{}", err.to_string().red()));
+            }
+        }
+    }
+    fn home(&self, version: Option<String>, current_dir: &Path, context: &'a ExecutorContext<'a, L>) {
+        match self.lang.home(version, current_dir, context) {
+            Ok((_, path)) => {
+                self.print_line(path.to_string_lossy());
+            },
+            Err(err) => {
+                error!("failed to execute `home` command:{}", err);
+                self.print_line(format!("failed to execute `home` command:\n\t{}", err.to_string().red()));
+            }
+        }
+    }
+    fn project_version(&self, installed_only: bool, current_dir: &Path, context: &ExecutorContext<'a, L>) -> Option<String> {
+        match self.lang.resolve_project_version(installed_only, current_dir, context) {
+            Ok((project, version)) => {
+                self.print_line(format!("found version {} required by {}.", version.green(), project.path.to_string_lossy()));
+                if let Some(hint) = project.hint {
+                    self.print_line(hint);
+                }
+                Some(version)
+            },
+            Err(err) => {
+                error!("failed to get project version:{}", err);
+                self.print_line(format!("failed to get project version:\n\t{}", err.to_string().red()));
+                None
+            }
+        }
+    }
+    fn reshim(&self, context: &'a ExecutorContext<'a, L>) {
+        match self.lang.reshim(context) {
+            Ok(_) => {
+                self.print_line(format!("regenerated shims of {} successfully.", self.lang.name()));
+            },
+            Err(err) => {
+                error!("failed to execute `reshim` command:{}", err);
+                self.print_line(format!("failed to execute `reshim` command:\n\t{}", err.to_string().red()));
+            }
+        }
+    }
+
+    fn upgrade(&self, version: Option<String>, prune: bool, context: &'a ExecutorContext<'a, L>) {
+        match self.lang.upgrade(version, prune, context) {
+            Ok(UpgradeResult::UpToDate(version)) => {
+                self.print_line(format!("{} {} is up to date.", self.lang.name(), version.green()));
+            },
+            Ok(UpgradeResult::Upgraded(from, to)) => {
+                self.print_line(format!("upgraded {} {} to {} successfully.", self.lang.name(), from, to.green()));
+                if prune {
+                    self.print_line(format!("uninstalled version {} successfully.", from));
+                }
+                self.regenerate_shims(context);
+            },
+            Err(err) => {
+                error!("failed to execute `upgrade` command:{}", err);
+                self.print_line(format!("failed to execute `upgrade` command:\n\t{}", err.to_string().red()));
+            }
+        }
+    }
 }
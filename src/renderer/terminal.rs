@@ -1,8 +1,23 @@
 use std::fmt::Display;
+use std::io::{IsTerminal, Write};
 
 /// TerminalRenderer displays output on terminal.
 pub trait TerminalRenderer {
     fn print_line<L: Display>(&self, line: L) {
         println!("{}", line);
     }
+
+    /// ask user to confirm `question`, it's always denied if stdin is not a terminal.
+    fn confirm<L: Display>(&self, question: L) -> bool {
+        if !std::io::stdin().is_terminal() {
+            return false;
+        }
+        print!("{} [y/N] ", question);
+        let _ = std::io::stdout().flush();
+        let mut answer = String::new();
+        match std::io::stdin().read_line(&mut answer) {
+            Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+            Err(_) => false,
+        }
+    }
 }
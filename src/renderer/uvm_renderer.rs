@@ -1,6 +1,7 @@
 use crate::executor::{LanguageContext, ExecutorContext};
 use crate::executor::{ConfigContext, ConfigKey};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// Renderer is used to render `Executor` output.
 pub trait UvmRenderer {
@@ -10,10 +11,21 @@ pub trait UvmRenderer {
 pub trait LanguageRenderer<'a, C: LanguageContext>: UvmRenderer {
     fn list(&self, local_only: bool, context: &'a ExecutorContext<'a, C>);
     fn install(&self, version: String, context: &'a ExecutorContext<'a, C>);
-    fn uninstall(&self, version: String, context: &'a ExecutorContext<'a, C>);
+    /// show details of the release matched by `version`.
+    fn info(&self, version: String, context: &'a ExecutorContext<'a, C>);
+    /// uninstall versions matched by `version`, all of them are uninstalled only if `all` is set or user confirms.
+    fn uninstall(&self, version: String, all: bool, context: &'a ExecutorContext<'a, C>);
     fn select(&self, version: String, context: &'a ExecutorContext<'a, C>);
     fn unuse(&self, context: &'a ExecutorContext<'a, C>);
     fn venv(&self, version: String, dir_name: String, context: &'a ExecutorContext<'a, C>);
+    fn current(&self, current_dir: &Path, context: &'a ExecutorContext<'a, C>);
+    fn local(&self, version: String, current_dir: &Path, context: &'a ExecutorContext<'a, C>);
+    fn home(&self, version: Option<String>, current_dir: &Path, context: &'a ExecutorContext<'a, C>);
+    fn reshim(&self, context: &'a ExecutorContext<'a, C>);
+    /// upgrade the installed version matched by `version`, or the used version, to the newest patch release.
+    fn upgrade(&self, version: Option<String>, prune: bool, context: &'a ExecutorContext<'a, C>);
+    /// get version required by the project in `current_dir`, `None` is returned if it fails.
+    fn project_version(&self, installed_only: bool, current_dir: &Path, context: &ExecutorContext<'a, C>) -> Option<String>;
 }
 
 /// ConfigRenderer executes `ConfigExecutor` and renders output.
@@ -21,4 +33,5 @@ pub trait ConfigRenderer: UvmRenderer {
     fn get(&self, keys: HashSet<ConfigKey>, context: ConfigContext);
     fn set(&self, kvs: HashMap<ConfigKey, String>, context: ConfigContext);
     fn del(&self, keys: HashSet<ConfigKey>, context: ConfigContext);
+    fn list(&self, show_origin: bool, context: ConfigContext);
 }
@@ -0,0 +1,206 @@
+use super::http::{self, HttpError};
+use super::logger::{debug, error};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// minutes a cached index is used without revalidation, if `index_ttl` is not configured.
+pub static DEFAULT_TTL_MINUTES: u64 = 60;
+
+/// IndexCache caches remote version indexes, like the download page of go or `index.json` of node.
+/// A cached index is used until it expires, then it's revalidated with `ETag` and `Last-Modified`.
+#[derive(Clone, Debug)]
+pub struct IndexCache {
+    dir: PathBuf,
+    ttl: Duration,
+    /// ignore cached indexes and download them again.
+    refresh: bool,
+    /// use cached indexes only, no matter whether they expire.
+    offline: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// seconds since unix epoch.
+    fetched_at: u64,
+    body: String,
+}
+
+impl IndexCache {
+    pub fn new(dir: &Path, ttl_minutes: u64) -> Self {
+        IndexCache {
+            dir: dir.to_path_buf(),
+            ttl: Duration::from_secs(ttl_minutes * 60),
+            refresh: false,
+            offline: false,
+        }
+    }
+
+    pub fn set_refresh(&mut self, refresh: bool) {
+        self.refresh = refresh;
+    }
+
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// try `urls` in order until one of them responds content accepted by `parse`, usually a mirror first
+    /// and then the official one, content is cached after it's parsed, so error pages of a mirror are never cached.
+    pub async fn download_html_from<T, E: Display>(
+        &self,
+        urls: &[String],
+        proxy: &Option<String>,
+        parse: impl Fn(&str) -> std::result::Result<T, E>,
+    ) -> http::Result<T> {
+        let mut last_error = HttpError::NoUrl();
+        for url in urls {
+            let error = match self.fetch(url, proxy).await {
+                Ok((entry, downloaded)) => match parse(&entry.body) {
+                    Ok(value) => {
+                        if downloaded {
+                            if let Err(e) = write_entry(&self.entry_path(url), &entry) {
+                                error!("failed to cache index of {}: {}", url, e);
+                            }
+                        }
+                        return Ok(value);
+                    }
+                    Err(e) => HttpError::InvalidContent(url.to_string(), e.to_string()),
+                },
+                Err(e) => e,
+            };
+            error!("failed to download {}: {}", url, error);
+            last_error = error;
+        }
+        Err(last_error)
+    }
+
+    /// get content of `url` from cache, or download it if the cache expires.
+    pub async fn download_html(&self, url: &str, proxy: &Option<String>) -> http::Result<String> {
+        self.download_html_from(&[url.to_string()], proxy, |body| Ok::<_, Infallible>(body.to_string()))
+            .await
+    }
+
+    /// get the entry of `url` from cache, or download it if the cache expires,
+    /// `true` means the entry is downloaded and should be cached.
+    async fn fetch(&self, url: &str, proxy: &Option<String>) -> http::Result<(CacheEntry, bool)> {
+        let path = self.entry_path(url);
+        if self.offline {
+            return match read_entry(&path) {
+                Some(entry) => Ok((entry, false)),
+                None => Err(HttpError::NotCached(url.to_string())),
+            };
+        }
+        let cached = if self.refresh { None } else { read_entry(&path) };
+        let now = now_secs();
+        if let Some(entry) = cached.as_ref().filter(|e| now.saturating_sub(e.fetched_at) < self.ttl.as_secs()) {
+            debug!("use cached index of {}", url);
+            return Ok((entry.clone(), false));
+        }
+        let response = http::download_html_if_modified(
+            url,
+            proxy,
+            cached.as_ref().and_then(|e| e.etag.as_deref()),
+            cached.as_ref().and_then(|e| e.last_modified.as_deref()),
+        )
+        .await?;
+        // a 304 response may omit validators, those of the cached index are still valid then
+        let (body, etag, last_modified) = match (response.body, cached) {
+            (Some(body), _) => (body, response.etag, response.last_modified),
+            (None, Some(entry)) => {
+                debug!("cached index of {} is not modified", url);
+                (
+                    entry.body,
+                    response.etag.or(entry.etag),
+                    response.last_modified.or(entry.last_modified),
+                )
+            }
+            (None, None) => return Err(HttpError::NotModified(url.to_string())),
+        };
+        let entry = CacheEntry {
+            url: url.to_string(),
+            etag,
+            last_modified,
+            fetched_at: now,
+            body,
+        };
+        Ok((entry, true))
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        let hash = Sha256::digest(url.as_bytes());
+        self.dir.join(format!("{:x}.json", hash))
+    }
+}
+
+fn read_entry(path: &Path) -> Option<CacheEntry> {
+    let content = fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(entry) => Some(entry),
+        Err(e) => {
+            error!("invalid cached index {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+fn write_entry(path: &Path, entry: &CacheEntry) -> std::io::Result<()> {
+    let content = serde_json::to_string(entry)?;
+    fs::write(path, content)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{now_secs, write_entry, CacheEntry, IndexCache};
+    use crate::tool::test_dir::TestDir;
+
+    #[test]
+    fn test_download_html() {
+        let test_dir = TestDir::new("index_cache");
+        let dir = test_dir.path();
+        // nothing listens on the discard port, so only cached content can be returned
+        let url = "http://127.0.0.1:9/index.json";
+        let mut cache = IndexCache::new(dir, 10);
+        let entry = CacheEntry {
+            url: url.to_string(),
+            etag: None,
+            last_modified: None,
+            fetched_at: now_secs(),
+            body: "[]".to_string(),
+        };
+        write_entry(&cache.entry_path(url), &entry).unwrap();
+        let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        assert_eq!("[]", rt.block_on(cache.download_html(url, &None)).unwrap());
+
+        cache.set_refresh(true);
+        assert!(rt.block_on(cache.download_html(url, &None)).is_err());
+
+        let mut expired = IndexCache::new(dir, 0);
+        assert!(rt.block_on(expired.download_html(url, &None)).is_err());
+        expired.set_offline(true);
+        assert_eq!("[]", rt.block_on(expired.download_html(url, &None)).unwrap());
+        assert!(rt.block_on(expired.download_html("http://127.0.0.1:9/other.json", &None)).is_err());
+
+        // content which cannot be parsed falls back to the next url
+        let mirror = "http://127.0.0.1:9/mirror/index.json";
+        let page = CacheEntry { url: mirror.to_string(), body: "<html>busy</html>".to_string(), ..entry };
+        write_entry(&cache.entry_path(mirror), &page).unwrap();
+        let urls = [mirror.to_string(), url.to_string()];
+        let parse = |body: &str| serde_json::from_str::<Vec<u32>>(body);
+        assert_eq!(Vec::<u32>::new(), rt.block_on(expired.download_html_from(&urls, &None, parse)).unwrap());
+        assert!(rt.block_on(expired.download_html_from(&urls[..1], &None, parse)).is_err());
+    }
+}
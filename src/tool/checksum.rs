@@ -11,28 +11,86 @@ pub enum ChecksumMethod {
     Md5,
 }
 
-pub fn verify(method: ChecksumMethod, file_path: &Path, expected: &str) -> io::Result<bool> {
-    let result = match method {
-        ChecksumMethod::None => true,
-        ChecksumMethod::Sha256 => expected.trim() == checksum_sha256(file_path)?,
-        ChecksumMethod::Md5 => expected.trim() == checksum_md5(file_path)?,
-    };
+/// Hasher computes a checksum incrementally, e.g. while a file is downloaded.
+pub enum Hasher {
+    None,
+    Sha256(Sha256),
+    Md5(Md5),
+}
+
+impl Hasher {
+    pub fn new(method: &ChecksumMethod) -> Self {
+        match method {
+            ChecksumMethod::None => Self::None,
+            ChecksumMethod::Sha256 => Self::Sha256(Sha256::new()),
+            ChecksumMethod::Md5 => Self::Md5(Md5::new()),
+        }
+    }
 
-    Ok(result)
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::None => {},
+            Self::Sha256(h) => h.update(data),
+            Self::Md5(h) => h.update(data),
+        }
+    }
+
+    /// get the hex checksum, `None` if the method is `ChecksumMethod::None`.
+    pub fn finalize(self) -> Option<String> {
+        match self {
+            Self::None => None,
+            Self::Sha256(h) => Some(format!("{:x}", h.finalize())),
+            Self::Md5(h) => Some(format!("{:x}", h.finalize())),
+        }
+    }
+
+    /// whether the checksum equals to `expected`, it's always true if the method is `ChecksumMethod::None`.
+    pub fn verify(self, expected: &str) -> bool {
+        self.finalize().is_none_or(|c| expected.trim() == c)
+    }
 }
 
-fn checksum_md5(file_path: &Path) -> io::Result<String> {
-    let mut file = File::open(file_path)?;
-    let mut hasher = Md5::new();
-    io::copy(&mut file, &mut hasher)?;
-    let hash = hasher.finalize();
-    Ok(format!("{:x}", hash))
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
-fn checksum_sha256(file_path: &Path) -> io::Result<String> {
+pub fn verify(method: ChecksumMethod, file_path: &Path, expected: &str) -> io::Result<bool> {
+    let mut hasher = Hasher::new(&method);
+    if let Hasher::None = hasher {
+        return Ok(true);
+    }
     let mut file = File::open(file_path)?;
-    let mut hasher = Sha256::new();
     io::copy(&mut file, &mut hasher)?;
-    let hash = hasher.finalize();
-    Ok(format!("{:x}", hash))
+    Ok(hasher.verify(expected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{verify, ChecksumMethod, Hasher};
+    use crate::tool::test_dir::TestDir;
+    use std::fs;
+
+    #[test]
+    fn test_hasher() {
+        let sha256 = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+        let mut hasher = Hasher::new(&ChecksumMethod::Sha256);
+        hasher.update(b"hello ");
+        hasher.update(b"world");
+        assert_eq!(Some(sha256.to_string()), hasher.finalize());
+
+        let test_dir = TestDir::new("checksum");
+        let file = test_dir.path().join("hello");
+        fs::write(&file, "hello world").unwrap();
+        assert!(verify(ChecksumMethod::Sha256, &file, &format!("{}\n", sha256)).unwrap());
+        assert!(verify(ChecksumMethod::Md5, &file, "5eb63bbbe01eeed093cb22bb8f5acdc3").unwrap());
+        assert!(!verify(ChecksumMethod::Md5, &file, sha256).unwrap());
+        assert!(verify(ChecksumMethod::None, &file, "").unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use super::logger::debug;
+use super::logger::{debug, error};
 use directories::UserDirs;
 use flate2::read::GzDecoder;
 use sevenz_rust;
@@ -18,6 +18,8 @@ pub type Result<T> = std::result::Result<T, FSError>;
 pub struct AppDir {
     home_dir: PathBuf,
     log_dir: PathBuf,
+    shims_dir: PathBuf,
+    data_dir: PathBuf,
     language_dirs: HashMap<String, LanguageDir>,
 }
 
@@ -27,64 +29,43 @@ pub struct LanguageDir {
     versions_dir: PathBuf,
     current_dir: PathBuf,
     tmp_dir: PathBuf,
+    cache_dir: PathBuf,
 }
 
 impl AppDir {
-    pub fn new() -> Result<Self> {
-        if let Some(user_dir) = UserDirs::new() {
-            let home = user_dir.home_dir();
-            let uvm_home = home.join(".uvm");
-            if !uvm_home.exists() || !uvm_home.is_dir() {
-                fs::create_dir(uvm_home.clone())?;
+    /// create uvm home and data directories, installed versions are stored in `data_dir`,
+    /// which is `~/.uvm/data` by default.
+    pub fn new(data_dir: Option<&str>) -> Result<Self> {
+        let app_dir = AppDir::open(data_dir)?;
+        for dir in [&app_dir.home_dir, &app_dir.log_dir, &app_dir.shims_dir] {
+            if !dir.exists() || !dir.is_dir() {
+                fs::create_dir(dir)?;
             }
-
-            let data_path = uvm_home.join("data");
-            if !data_path.exists() || !data_path.is_dir() {
-                fs::create_dir(data_path.clone())?;
-            }
-
-            let log_path = uvm_home.join("log");
-            if !log_path.exists() || !log_path.is_dir() {
-                fs::create_dir(log_path.clone())?;
-            }
-
-            let mut language_dirs = HashMap::new();
-            for name in ["go", "java", "node"] {
-                let lang_dir = data_path.join(name);
-                if !lang_dir.exists() || !lang_dir.is_dir() {
-                    fs::create_dir(lang_dir.clone())?;
-                }
-                let versions_path = lang_dir.join("versions");
-                if !versions_path.exists() || !versions_path.is_dir() {
-                    fs::create_dir(versions_path.clone())?;
-                }
-                let tmp_path = lang_dir.join("tmp");
-                if !tmp_path.exists() || !tmp_path.is_dir() {
-                    fs::create_dir(tmp_path.clone())?;
-                }
-                let current_dir = lang_dir.join("current");
-                if !current_dir.exists() || !current_dir.is_dir() {
-                    fs::create_dir(current_dir.clone())?;
-                }
-                language_dirs.insert(
-                    name.to_string(),
-                    LanguageDir {
-                        home_dir: lang_dir,
-                        versions_dir: versions_path,
-                        current_dir,
-                        tmp_dir: tmp_path,
-                    },
-                );
-            }
-
-            Ok(AppDir {
-                home_dir: uvm_home,
-                log_dir: log_path,
-                language_dirs,
-            })
-        } else {
-            Err(FSError::AppDirError())
         }
+        if !app_dir.data_dir.exists() || !app_dir.data_dir.is_dir() {
+            fs::create_dir_all(&app_dir.data_dir)?;
+        }
+        for name in LANGUAGE_NAMES {
+            LanguageDir::new(&app_dir.data_dir, name)?;
+        }
+        Ok(app_dir)
+    }
+
+    /// locate uvm home and data directories without creating anything, e.g. for shims.
+    pub fn open(data_dir: Option<&str>) -> Result<Self> {
+        let uvm_home = get_uvm_home()?;
+        let data_path = resolve_data_dir(&uvm_home, data_dir);
+        let language_dirs = LANGUAGE_NAMES
+            .iter()
+            .map(|name| (name.to_string(), LanguageDir::open(&data_path, name)))
+            .collect();
+        Ok(AppDir {
+            log_dir: uvm_home.join("log"),
+            shims_dir: uvm_home.join("shims"),
+            home_dir: uvm_home,
+            data_dir: data_path,
+            language_dirs,
+        })
     }
 
     pub fn get_home_dir(&self) -> &Path {
@@ -95,12 +76,76 @@ impl AppDir {
         self.log_dir.as_path()
     }
 
+    pub fn get_shims_dir(&self) -> &Path {
+        self.shims_dir.as_path()
+    }
+
+    pub fn get_data_dir(&self) -> &Path {
+        self.data_dir.as_path()
+    }
+
     pub fn get_language_dir(&self, name: &str) -> Option<&LanguageDir> {
         self.language_dirs.get(name)
     }
+
+    /// move all installed versions and downloaded files to `to_dir`,
+    /// `current` links and links of virtual environments are re-pointed to the new location.
+    pub fn migrate_data(&self, to_dir: &Path) -> Result<AppDir> {
+        let data_dir = to_dir.to_str().ok_or(FSError::InvalidPath())?;
+        if to_dir == self.data_dir
+            || self.language_dirs.values().any(|d| to_dir.starts_with(&d.home_dir))
+        {
+            return Err(FSError::InvalidDataDir(to_dir.to_path_buf()));
+        }
+        let new_app_dir = AppDir::new(Some(data_dir))?;
+        let pairs: Vec<_> = LANGUAGE_NAMES
+            .iter()
+            .map(|name| (&self.language_dirs[*name], &new_app_dir.language_dirs[*name]))
+            .collect();
+        migrate_languages(&pairs)?;
+        if fs::remove_dir(&self.data_dir).is_err() {
+            debug!("old data dir {:?} is not empty, keep it", self.data_dir);
+        }
+        Ok(new_app_dir)
+    }
 }
 
+static LANGUAGE_NAMES: [&str; 3] = ["go", "java", "node"];
+
+static VENVS_FILE: &str = "venvs";
+
 impl LanguageDir {
+    pub fn new(data_path: &Path, name: &str) -> Result<Self> {
+        let language_dir = LanguageDir::open(data_path, name);
+        for dir in [&language_dir.home_dir, &language_dir.versions_dir, &language_dir.tmp_dir, &language_dir.cache_dir] {
+            if !dir.exists() || !dir.is_dir() {
+                fs::create_dir(dir)?;
+            }
+        }
+        // `current` is a link created by `use`/`install`,
+        // an empty folder may be left by previous versions of uvm, which prevents making the link.
+        let current_dir = &language_dir.current_dir;
+        if !current_dir.is_symlink()
+            && current_dir.is_dir()
+            && fs::read_dir(current_dir)?.next().is_none()
+        {
+            fs::remove_dir(current_dir)?;
+        }
+        Ok(language_dir)
+    }
+
+    /// locate directories of language `name` without creating them.
+    fn open(data_path: &Path, name: &str) -> Self {
+        let lang_dir = data_path.join(name);
+        LanguageDir {
+            versions_dir: lang_dir.join("versions"),
+            current_dir: lang_dir.join("current"),
+            tmp_dir: lang_dir.join("tmp"),
+            cache_dir: lang_dir.join("cache"),
+            home_dir: lang_dir,
+        }
+    }
+
     pub fn get_home_dir(&self) -> &Path {
         self.home_dir.as_path()
     }
@@ -116,6 +161,232 @@ impl LanguageDir {
     pub fn get_tmp_dir(&self) -> &Path {
         self.tmp_dir.as_path()
     }
+
+    /// cached remote version indexes.
+    pub fn get_cache_dir(&self) -> &Path {
+        self.cache_dir.as_path()
+    }
+
+    /// record the link of a virtual environment, so it can be re-pointed when data dir is migrated.
+    pub fn add_venv(&self, link_dir: &Path) -> Result<()> {
+        let mut venvs = self.get_venvs()?;
+        if !venvs.iter().any(|v| v == link_dir) {
+            venvs.push(link_dir.to_path_buf());
+            self.save_venvs(&venvs)?;
+        }
+        Ok(())
+    }
+
+    /// get links of virtual environments which still exist.
+    pub fn get_venvs(&self) -> Result<Vec<PathBuf>> {
+        let venvs_file = self.home_dir.join(VENVS_FILE);
+        if !venvs_file.is_file() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(venvs_file)?;
+        Ok(content
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(PathBuf::from)
+            .filter(|p| p.is_symlink())
+            .collect())
+    }
+
+    fn save_venvs(&self, venvs: &[PathBuf]) -> Result<()> {
+        let content: Vec<_> = venvs.iter().filter_map(|v| v.to_str()).collect();
+        fs::write(self.home_dir.join(VENVS_FILE), content.join("\n"))?;
+        Ok(())
+    }
+
+    /// get installed versions, downloaded files and other files(e.g. `go_path` of Golang),
+    /// paired with their destinations in `to`.
+    fn migrate_entries(&self, to: &LanguageDir) -> Result<Vec<(PathBuf, PathBuf)>> {
+        let mut entries = vec![];
+        for (from_dir, to_dir) in [(&self.versions_dir, &to.versions_dir), (&self.tmp_dir, &to.tmp_dir)] {
+            for entry in fs::read_dir(from_dir)? {
+                let entry = entry?;
+                entries.push((entry.path(), to_dir.join(entry.file_name())));
+            }
+        }
+        let managed = [&self.versions_dir, &self.tmp_dir, &self.cache_dir, &self.current_dir];
+        for entry in fs::read_dir(&self.home_dir)? {
+            let path = entry?.path();
+            if !managed.contains(&&path) && !path.ends_with(VENVS_FILE) {
+                entries.push((path.clone(), to.home_dir.join(path.file_name().unwrap_or_default())));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// re-point `current` link and links of virtual environments to `to`, and remove this folder,
+    /// data has been moved already, so failures are logged instead of stopping the migration.
+    fn relink_to(&self, to: &LanguageDir) {
+        if let Some(name) = get_link_name(&self.current_dir) {
+            let result = remove_link(&self.current_dir)
+                .and_then(|_| if to.current_dir.exists() { remove_current(&to.current_dir) } else { Ok(()) })
+                .and_then(|_| make_link(&to.current_dir, &to.versions_dir.join(name)));
+            if let Err(err) = result {
+                error!("failed to re-point {:?}: {}", to.current_dir, err);
+            }
+        }
+
+        let venvs = self.get_venvs().unwrap_or_else(|err| {
+            error!("failed to read virtual environments of {:?}: {}", self.home_dir, err);
+            vec![]
+        });
+        for venv in &venvs {
+            if let Err(err) = relink(venv, &self.versions_dir, &to.versions_dir) {
+                error!("failed to re-point venv {:?}: {}", venv, err);
+            }
+        }
+        if let Err(err) = to.save_venvs(&venvs) {
+            error!("failed to save virtual environments of {:?}: {}", to.home_dir, err);
+        }
+
+        if let Err(err) = fs::remove_dir_all(&self.home_dir) {
+            error!("failed to remove {:?}: {}", self.home_dir, err);
+        }
+    }
+}
+
+/// re-point `link` to the same version in `to_versions` if it links to a version in `from_versions`.
+fn relink(link: &Path, from_versions: &Path, to_versions: &Path) -> Result<()> {
+    let linked = fs::read_link(link)?;
+    if let Some(name) = linked.file_name().filter(|_| linked.starts_with(from_versions)) {
+        debug!("re-point venv {:?}", link);
+        remove_link(link)?;
+        make_link(link, &to_versions.join(name))?;
+    }
+    Ok(())
+}
+
+/// move data of each `(from, to)` language pair, all destinations are checked before anything is moved,
+/// and moved entries are moved back if any of them fails.
+fn migrate_languages(pairs: &[(&LanguageDir, &LanguageDir)]) -> Result<()> {
+    let mut entries = vec![];
+    for (from, to) in pairs {
+        debug!("migrate {:?} to {:?}", from.home_dir, to.home_dir);
+        entries.extend(from.migrate_entries(to)?);
+    }
+    if let Some((_, dest)) = entries.iter().find(|(_, dest)| dest.exists()) {
+        return Err(FSError::TargetExists(dest.clone()));
+    }
+    for (i, (src, dest)) in entries.iter().enumerate() {
+        if let Err(err) = move_path(src, dest) {
+            for (src, dest) in entries[..i].iter().rev() {
+                if let Err(err) = move_path(dest, src) {
+                    error!("failed to move {:?} back to {:?}: {}", dest, src, err);
+                }
+            }
+            return Err(err);
+        }
+    }
+    for (from, to) in pairs {
+        from.relink_to(to);
+    }
+    Ok(())
+}
+
+/// get uvm home, i.e. `~/.uvm`.
+pub fn get_uvm_home() -> Result<PathBuf> {
+    match UserDirs::new() {
+        Some(user_dir) => Ok(user_dir.home_dir().join(".uvm")),
+        None => Err(FSError::AppDirError()),
+    }
+}
+
+/// remove `.` and `..` from `path` without accessing file system.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                result.pop();
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// `data_dir` could be absolute, starts with `~`, or relative to uvm home.
+fn resolve_data_dir(uvm_home: &Path, data_dir: Option<&str>) -> PathBuf {
+    match data_dir {
+        Some(dir) if !dir.is_empty() => {
+            let rest = if dir == "~" { Some("") } else { dir.strip_prefix("~/").or(dir.strip_prefix("~\\")) };
+            if let (Some(rest), Some(user_dir)) = (rest, UserDirs::new()) {
+                let user_home = user_dir.home_dir();
+                return if rest.is_empty() { user_home.to_path_buf() } else { user_home.join(rest) };
+            }
+            uvm_home.join(dir)
+        }
+        _ => uvm_home.join("data"),
+    }
+}
+
+fn get_link_name(link: &Path) -> Option<std::ffi::OsString> {
+    if link.is_symlink() {
+        fs::read_link(link).ok().and_then(|p| p.file_name().map(|n| n.to_os_string()))
+    } else {
+        None
+    }
+}
+
+/// `current` may be an empty folder left by previous versions of uvm.
+fn remove_current(current_dir: &Path) -> Result<()> {
+    if current_dir.is_symlink() {
+        remove_link(current_dir)
+    } else {
+        fs::remove_dir(current_dir)?;
+        Ok(())
+    }
+}
+
+/// rename `from` to `to`, copy and delete if they are on different file systems.
+fn move_path(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).is_err() {
+        debug!("failed to rename {:?}, copy it", from);
+        copy_path(from, to)?;
+        if from.is_dir() && !from.is_symlink() {
+            fs::remove_dir_all(from)?;
+        } else {
+            fs::remove_file(from)?;
+        }
+    }
+    Ok(())
+}
+
+fn copy_path(from: &Path, to: &Path) -> Result<()> {
+    let metadata = fs::symlink_metadata(from)?;
+    if metadata.is_symlink() {
+        copy_symlink(from, to)?;
+    } else if metadata.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy_path(&entry.path(), &to.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(from, to)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn copy_symlink(from: &Path, to: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(from)?, to)?;
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn copy_symlink(from: &Path, to: &Path) -> Result<()> {
+    if from.is_dir() {
+        std::os::windows::fs::symlink_dir(fs::read_link(from)?, to)?;
+    } else {
+        std::os::windows::fs::symlink_file(fs::read_link(from)?, to)?;
+    }
+    Ok(())
 }
 
 pub fn decompress(file_path: &Path, to_path: &Path) -> Result<Option<String>> {
@@ -307,8 +578,73 @@ pub enum FSError {
     Zip(#[from] zip::result::ZipError),
     #[error("{0}")]
     SevenZ(#[from] sevenz_rust::Error),
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
     #[error("make symbol link error")]
     LinkError(),
     #[error("file type '{0}' is not supported")]
     UnsupportedFile(&'static str),
+    #[error("path is not valid unicode")]
+    InvalidPath(),
+    #[error("cannot migrate data to {0:?}")]
+    InvalidDataDir(PathBuf),
+    #[error("{0:?} already exists")]
+    TargetExists(PathBuf),
+}
+
+#[cfg(test)]
+mod test {
+    use super::{migrate_languages, normalize_path, resolve_data_dir, FSError, LanguageDir};
+    use directories::UserDirs;
+    use crate::tool::test_dir::TestDir;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(PathBuf::from("/a/c"), normalize_path(Path::new("/a/b/../c")));
+        assert_eq!(PathBuf::from("/a/b"), normalize_path(Path::new("/a/./b/")));
+    }
+
+    #[test]
+    fn test_resolve_data_dir() {
+        let home = Path::new("/home/u/.uvm");
+        assert_eq!(home.join("data"), resolve_data_dir(home, None));
+        assert_eq!(home.join("data"), resolve_data_dir(home, Some("")));
+        assert_eq!(PathBuf::from("/opt/uvm"), resolve_data_dir(home, Some("/opt/uvm")));
+        assert_eq!(home.join("store"), resolve_data_dir(home, Some("store")));
+        let user_home = UserDirs::new().unwrap().home_dir().to_path_buf();
+        assert_eq!(user_home, resolve_data_dir(home, Some("~")));
+        assert_eq!(user_home.join("uvm"), resolve_data_dir(home, Some("~/uvm")));
+    }
+
+    #[test]
+    fn test_migrate_languages() {
+        let test_dir = TestDir::new("migrate_languages");
+        let root = test_dir.path();
+        let (from_data, to_data) = (root.join("from"), root.join("to"));
+        fs::create_dir_all(&from_data).unwrap();
+        fs::create_dir_all(&to_data).unwrap();
+        let from_go = LanguageDir::new(&from_data, "go").unwrap();
+        let from_node = LanguageDir::new(&from_data, "node").unwrap();
+        let to_go = LanguageDir::new(&to_data, "go").unwrap();
+        let to_node = LanguageDir::new(&to_data, "node").unwrap();
+        fs::create_dir(from_go.get_versions_dir().join("1.21.6")).unwrap();
+        fs::create_dir(from_go.get_home_dir().join("go_path")).unwrap();
+        fs::create_dir(from_node.get_versions_dir().join("20.11.0")).unwrap();
+        fs::create_dir(to_node.get_versions_dir().join("20.11.0")).unwrap();
+
+        let pairs = [(&from_go, &to_go), (&from_node, &to_node)];
+        assert!(matches!(migrate_languages(&pairs), Err(FSError::TargetExists(_))));
+        assert!(from_go.get_versions_dir().join("1.21.6").is_dir());
+        assert!(!to_go.get_versions_dir().join("1.21.6").exists());
+
+        fs::remove_dir(to_node.get_versions_dir().join("20.11.0")).unwrap();
+        // a venv which cannot be re-pointed doesn't fail the migration
+        from_go.add_venv(&root.join("missing_venv").join("go")).unwrap();
+        migrate_languages(&pairs).unwrap();
+        assert!(to_go.get_versions_dir().join("1.21.6").is_dir());
+        assert!(to_node.get_versions_dir().join("20.11.0").is_dir());
+        assert!(to_go.get_home_dir().join("go_path").is_dir());
+        assert!(!from_go.get_home_dir().exists());
+    }
 }
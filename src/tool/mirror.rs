@@ -0,0 +1,68 @@
+/// Mirror rewrites urls of an official site to a mirror site,
+/// the official url is always kept as a fallback in case the mirror fails.
+#[derive(Clone, Debug)]
+pub struct Mirror {
+    official: &'static str,
+    mirror: Option<String>,
+}
+
+impl Mirror {
+    /// `official` is the base url of the official site, e.g. `https://nodejs.org/dist/`.
+    pub fn new(official: &'static str, mirror: Option<&String>) -> Self {
+        let mirror = mirror.filter(|m| !m.is_empty()).map(|m| {
+            if m.ends_with('/') {
+                m.clone()
+            } else {
+                format!("{}/", m)
+            }
+        });
+        Mirror { official, mirror }
+    }
+
+    /// urls of `path` relative to the base url, the mirror comes first.
+    pub fn urls(&self, path: &str) -> Vec<String> {
+        let path = path.trim_start_matches('/');
+        let mut result = Vec::new();
+        if let Some(m) = &self.mirror {
+            result.push(format!("{}{}", m, path));
+        }
+        result.push(format!("{}{}", self.official, path));
+        result
+    }
+
+    /// rewrite an absolute url of the official site, urls of other sites are returned as they are.
+    pub fn rewrite(&self, url: &str) -> Vec<String> {
+        match url.strip_prefix(self.official) {
+            Some(path) => self.urls(path),
+            None => vec![url.to_string()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Mirror;
+
+    #[test]
+    fn test_mirror_urls() {
+        let mirror = Mirror::new("https://nodejs.org/dist/", Some(&"https://npmmirror.com/mirrors/node".to_string()));
+        assert_eq!(
+            vec![
+                "https://npmmirror.com/mirrors/node/index.json".to_string(),
+                "https://nodejs.org/dist/index.json".to_string()
+            ],
+            mirror.urls("index.json")
+        );
+        assert_eq!(
+            vec![
+                "https://npmmirror.com/mirrors/node/v20.10.0/SHASUMS256.txt".to_string(),
+                "https://nodejs.org/dist/v20.10.0/SHASUMS256.txt".to_string()
+            ],
+            mirror.rewrite("https://nodejs.org/dist/v20.10.0/SHASUMS256.txt")
+        );
+        assert_eq!(vec!["https://a.com/b".to_string()], mirror.rewrite("https://a.com/b"));
+
+        let official = Mirror::new("https://go.dev/dl/", None);
+        assert_eq!(vec!["https://go.dev/dl/go1.21.6.src.tar.gz".to_string()], official.urls("/go1.21.6.src.tar.gz"));
+    }
+}
@@ -1,9 +1,16 @@
 mod supported_language;
 
 pub mod args;
+pub mod cache;
 pub mod http;
 pub mod logger;
 pub mod fs;
 pub mod checksum;
+pub mod mirror;
+pub mod process;
+pub mod shell;
+pub mod shim;
+#[cfg(test)]
+pub mod test_dir;
 
 pub use supported_language::{SupportedLanguage, GO, JAVA, NODE};
\ No newline at end of file
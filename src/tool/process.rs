@@ -0,0 +1,37 @@
+use std::ffi::OsStr;
+use std::io::Result;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// execute `program` with `args`, `paths` are prepended to `PATH` and `vars` are set,
+/// `program` is searched in the new `PATH`.
+/// on Unix, current process is replaced, otherwise it returns the exit code of the child process.
+pub fn exec<S: AsRef<OsStr>>(
+    program: S,
+    args: &[String],
+    paths: &[PathBuf],
+    vars: &[(&str, PathBuf)],
+) -> Result<i32> {
+    let mut all_paths = paths.to_vec();
+    if let Some(path) = std::env::var_os("PATH") {
+        all_paths.extend(std::env::split_paths(&path));
+    }
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .env("PATH", std::env::join_paths(all_paths).unwrap_or_default())
+        .envs(vars.iter().map(|(k, v)| (k, v)));
+    run(command)
+}
+
+#[cfg(not(windows))]
+fn run(mut command: Command) -> Result<i32> {
+    use std::os::unix::process::CommandExt;
+    Err(command.exec())
+}
+
+#[cfg(windows)]
+fn run(mut command: Command) -> Result<i32> {
+    let status = command.status()?;
+    Ok(status.code().unwrap_or(1))
+}
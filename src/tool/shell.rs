@@ -0,0 +1,297 @@
+use std::path::{Path, PathBuf};
+
+/// paths added to `PATH` by uvm, used to remove them when versions change.
+pub static PATHS_ENV: &str = "UVM_ENV_PATHS";
+/// names of variables set by uvm, used to unset them when versions change.
+pub static VARS_ENV: &str = "UVM_ENV_VARS";
+/// prefix of variables which save values replaced by uvm, they are restored when versions change.
+pub static PRE_ENV_PREFIX: &str = "UVM_PRE_";
+/// the directory which environment variables are applied for.
+pub static DIR_ENV: &str = "UVM_ENV_DIR";
+/// the shell which the hook is installed in, set by `uvm init`.
+pub static SHELL_ENV: &str = "UVM_SHELL";
+/// command whose output is evaluated by the `uvm` function of the hook.
+pub static SHELL_COMMAND: &str = "shell";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Pwsh,
+}
+
+impl Shell {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "bash" => Some(Self::Bash),
+            "zsh" => Some(Self::Zsh),
+            "fish" => Some(Self::Fish),
+            "pwsh" | "powershell" => Some(Self::Pwsh),
+            _ => None,
+        }
+    }
+
+    /// the shell which the hook is installed in.
+    pub fn from_env() -> Option<Self> {
+        std::env::var(SHELL_ENV).ok().and_then(|s| Self::from_str(&s))
+    }
+
+    /// the default shell of current OS, used when the hook is not installed.
+    pub fn default_shell() -> Self {
+        if cfg!(windows) {
+            Self::Pwsh
+        } else {
+            Self::Bash
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Zsh => "zsh",
+            Self::Fish => "fish",
+            Self::Pwsh => "pwsh",
+        }
+    }
+
+    /// hook which applies environment variables from `uvm env` before each prompt,
+    /// and wraps uvm by a function which evaluates the output of `uvm shell`.
+    pub fn hook(&self, uvm_exe: &Path) -> String {
+        let uvm = uvm_exe.to_string_lossy();
+        let name = self.name();
+        match self {
+            Self::Bash | Self::Zsh => format!(
+                r#"export {SHELL_ENV}={name}
+uvm() {{
+  if [ "$1" = "{SHELL_COMMAND}" ]; then
+    eval "$("{uvm}" "$@")"
+  else
+    "{uvm}" "$@"
+  fi
+}}
+{}"#,
+                self.prompt_hook(&uvm)
+            ),
+            Self::Fish => format!(
+                r#"set -gx {SHELL_ENV} {name}
+function uvm
+    if test "$argv[1]" = {SHELL_COMMAND}
+        "{uvm}" $argv | source
+    else
+        "{uvm}" $argv
+    end
+end
+function __uvm_hook --on-variable PWD --on-event fish_prompt
+    "{uvm}" env fish | source
+end
+__uvm_hook
+"#
+            ),
+            Self::Pwsh => format!(
+                r#"$env:{SHELL_ENV} = '{name}'
+function global:uvm {{
+    if ($args[0] -eq '{SHELL_COMMAND}') {{
+        & '{0}' @args | Out-String | Invoke-Expression
+    }} else {{
+        & '{0}' @args
+    }}
+}}
+if (-not (Test-Path function:__uvm_original_prompt)) {{
+    $function:__uvm_original_prompt = $function:prompt
+}}
+function global:prompt {{
+    & '{0}' env pwsh | Out-String | Invoke-Expression
+    & $function:__uvm_original_prompt
+}}
+"#,
+                uvm.replace('\'', "''")
+            ),
+        }
+    }
+
+    fn prompt_hook(&self, uvm: &str) -> String {
+        match self {
+            Self::Zsh => format!(
+                r#"_uvm_hook() {{
+  eval "$("{uvm}" env zsh)"
+}}
+typeset -ag precmd_functions chpwd_functions
+if (( ! ${{precmd_functions[(I)_uvm_hook]}} )); then
+  precmd_functions=(_uvm_hook $precmd_functions)
+fi
+if (( ! ${{chpwd_functions[(I)_uvm_hook]}} )); then
+  chpwd_functions=(_uvm_hook $chpwd_functions)
+fi
+"#
+            ),
+            _ => format!(
+                r#"_uvm_hook() {{
+  local previous_exit_status=$?
+  eval "$("{uvm}" env bash)"
+  return $previous_exit_status
+}}
+if [[ ";${{PROMPT_COMMAND[*]:-}};" != *";_uvm_hook;"* ]]; then
+  PROMPT_COMMAND="_uvm_hook${{PROMPT_COMMAND:+;$PROMPT_COMMAND}}"
+fi
+"#
+            ),
+        }
+    }
+
+    /// statements to apply `changes`, a variable is unset if its value is `None`.
+    pub fn format_changes(&self, changes: &[(String, Option<String>)]) -> String {
+        let mut result = String::new();
+        for (name, value) in changes {
+            let statement = match (self, value) {
+                (Self::Bash | Self::Zsh, Some(v)) => format!("export {}={};", name, quote_posix(v)),
+                (Self::Bash | Self::Zsh, None) => format!("unset {};", name),
+                (Self::Fish, Some(v)) if name == "PATH" => {
+                    let paths: Vec<_> = std::env::split_paths(v)
+                        .map(|p| quote_posix(&p.to_string_lossy()))
+                        .collect();
+                    format!("set -gx PATH {};", paths.join(" "))
+                }
+                (Self::Fish, Some(v)) => format!("set -gx {} {};", name, quote_posix(v)),
+                (Self::Fish, None) => format!("set -e {};", name),
+                (Self::Pwsh, Some(v)) => format!("$env:{} = '{}';", name, v.replace('\'', "''")),
+                (Self::Pwsh, None) => format!("Remove-Item Env:{} -ErrorAction SilentlyContinue;", name),
+            };
+            result.push_str(&statement);
+            result.push('\n');
+        }
+        result
+    }
+}
+
+fn quote_posix(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r#"'\''"#))
+}
+
+/// get changes of environment variables to apply `paths` and `vars` for `dir`,
+/// paths and variables applied before are replaced, variables which are not applied any more get back
+/// the values they had before uvm set them, `current` gets the current value of a variable.
+pub fn get_env_changes<F: Fn(&str) -> Option<String>>(
+    current: F,
+    dir: &Path,
+    paths: &[PathBuf],
+    vars: &[(&str, PathBuf)],
+) -> Vec<(String, Option<String>)> {
+    let mut expected: Vec<(String, Option<String>)> = Vec::new();
+
+    let old_paths: Vec<PathBuf> = current(PATHS_ENV)
+        .map(|p| std::env::split_paths(&p).collect())
+        .unwrap_or_default();
+    let mut all_paths = paths.to_vec();
+    if let Some(path) = current("PATH") {
+        all_paths.extend(std::env::split_paths(&path).filter(|p| !old_paths.contains(p)));
+    }
+    expected.push(("PATH".to_string(), join_paths(&all_paths)));
+    expected.push((PATHS_ENV.to_string(), join_paths(paths)));
+
+    let old_vars = current(VARS_ENV).unwrap_or_default();
+    let old_vars: Vec<_> = old_vars.split(',').filter(|n| !n.is_empty()).collect();
+    for name in &old_vars {
+        if !vars.iter().any(|(n, _)| n == name) {
+            let pre_name = format!("{}{}", PRE_ENV_PREFIX, name);
+            expected.push((name.to_string(), current(&pre_name)));
+            expected.push((pre_name, None));
+        }
+    }
+    for (name, value) in vars {
+        if !old_vars.contains(name) {
+            expected.push((format!("{}{}", PRE_ENV_PREFIX, name), current(name)));
+        }
+        expected.push((name.to_string(), Some(value.to_string_lossy().to_string())));
+    }
+    let names: Vec<_> = vars.iter().map(|(n, _)| *n).collect();
+    expected.push((VARS_ENV.to_string(), Some(names.join(",")).filter(|n| !n.is_empty())));
+    expected.push((DIR_ENV.to_string(), Some(dir.to_string_lossy().to_string())));
+
+    expected
+        .into_iter()
+        .filter(|(name, value)| current(name) != *value)
+        .collect()
+}
+
+fn join_paths(paths: &[PathBuf]) -> Option<String> {
+    if paths.is_empty() {
+        return None;
+    }
+    std::env::join_paths(paths)
+        .ok()
+        .map(|p| p.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_env_changes, Shell, DIR_ENV, PATHS_ENV, VARS_ENV};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_get_env_changes() {
+        let mut env: HashMap<String, String> = HashMap::new();
+        env.insert("PATH".to_string(), "/old/go/bin:/usr/bin".to_string());
+        env.insert(PATHS_ENV.to_string(), "/old/go/bin".to_string());
+        env.insert(VARS_ENV.to_string(), "GOROOT,JAVA_HOME".to_string());
+        env.insert("GOROOT".to_string(), "/old/go".to_string());
+        env.insert("JAVA_HOME".to_string(), "/old/java".to_string());
+        let dir = Path::new("/project");
+        let paths = vec![PathBuf::from("/new/go/bin")];
+        let vars = vec![("GOROOT", PathBuf::from("/new/go"))];
+
+        let changes = get_env_changes(|n| env.get(n).cloned(), dir, &paths, &vars);
+        let changes: HashMap<_, _> = changes.into_iter().collect();
+        assert_eq!(Some(&Some("/new/go/bin:/usr/bin".to_string())), changes.get("PATH"));
+        assert_eq!(Some(&None), changes.get("JAVA_HOME"));
+        assert_eq!(Some(&Some("/new/go".to_string())), changes.get("GOROOT"));
+        assert_eq!(Some(&Some("GOROOT".to_string())), changes.get(VARS_ENV));
+
+        for (name, value) in changes {
+            match value {
+                Some(v) => env.insert(name, v),
+                None => env.remove(&name),
+            };
+        }
+        assert!(get_env_changes(|n| env.get(n).cloned(), dir, &paths, &vars).is_empty());
+        assert_eq!(Some(&"/project".to_string()), env.get(DIR_ENV));
+    }
+
+    #[test]
+    fn test_restore_variables() {
+        let apply = |env: &mut HashMap<String, String>, vars: &[(&str, PathBuf)]| {
+            for (name, value) in get_env_changes(|n| env.get(n).cloned(), Path::new("/project"), &[], vars) {
+                match value {
+                    Some(v) => env.insert(name, v),
+                    None => env.remove(&name),
+                };
+            }
+        };
+        let mut env: HashMap<String, String> = HashMap::new();
+        env.insert("GOROOT".to_string(), "/pre/go".to_string());
+
+        apply(&mut env, &[("GOROOT", PathBuf::from("/new/go")), ("JAVA_HOME", PathBuf::from("/new/java"))]);
+        assert_eq!(Some(&"/new/go".to_string()), env.get("GOROOT"));
+        apply(&mut env, &[("GOROOT", PathBuf::from("/other/go"))]);
+        assert_eq!(Some(&"/other/go".to_string()), env.get("GOROOT"));
+        assert_eq!(None, env.get("JAVA_HOME"));
+        apply(&mut env, &[]);
+        assert_eq!(Some(&"/pre/go".to_string()), env.get("GOROOT"));
+        assert!(env.keys().all(|n| n == "GOROOT" || n == DIR_ENV));
+    }
+
+    #[test]
+    fn test_format_changes() {
+        let changes = vec![
+            ("GOROOT".to_string(), Some("/go's".to_string())),
+            ("JAVA_HOME".to_string(), None),
+        ];
+        assert_eq!("export GOROOT='/go'\\''s';\nunset JAVA_HOME;\n", Shell::Bash.format_changes(&changes));
+        assert_eq!("set -gx GOROOT '/go'\\''s';\nset -e JAVA_HOME;\n", Shell::Fish.format_changes(&changes));
+        assert_eq!(
+            "$env:GOROOT = '/go''s';\nRemove-Item Env:JAVA_HOME -ErrorAction SilentlyContinue;\n",
+            Shell::Pwsh.format_changes(&changes)
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeSet;
+use std::fs::{read_dir, read_to_string, remove_file, write};
+use std::io::Result;
+use std::path::{Path, PathBuf};
+
+/// hidden command executed by shims, e.g. `uvm shim go gofmt -l .`.
+pub static SHIM_COMMAND: &str = "shim";
+
+/// write shims for executables `names` of `lang`, existing shims of `lang` are replaced,
+/// shims of other languages are kept.
+pub fn write_shims(shims_dir: &Path, uvm_exe: &Path, lang: &str, names: &BTreeSet<String>) -> Result<()> {
+    let marker = shim_marker(lang);
+    for entry in read_dir(shims_dir)? {
+        let path = entry?.path();
+        if path.is_file() && read_to_string(&path).is_ok_and(|c| c.contains(&marker)) {
+            remove_file(&path)?;
+        }
+    }
+    for name in names {
+        write_shim(shims_dir, uvm_exe, lang, name)?;
+    }
+    Ok(())
+}
+
+fn shim_marker(lang: &str) -> String {
+    format!(" {} {} ", SHIM_COMMAND, lang)
+}
+
+#[cfg(not(windows))]
+fn write_shim(shims_dir: &Path, uvm_exe: &Path, lang: &str, name: &str) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let path = shims_dir.join(name);
+    let content = format!(
+        "#!/bin/sh\nexec \"{}\"{}{} \"$@\"\n",
+        uvm_exe.to_string_lossy(),
+        shim_marker(lang),
+        name
+    );
+    write(&path, content)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(windows)]
+fn write_shim(shims_dir: &Path, uvm_exe: &Path, lang: &str, name: &str) -> Result<()> {
+    let path = shims_dir.join(format!("{}.cmd", name));
+    let content = format!(
+        "@echo off\r\n\"{}\"{}{} %*\r\n",
+        uvm_exe.to_string_lossy(),
+        shim_marker(lang),
+        name
+    );
+    write(path, content)
+}
+
+/// names of executables in `dir`, extensions are removed on Windows.
+pub fn get_executable_names(dir: &Path) -> Result<BTreeSet<String>> {
+    let mut result = BTreeSet::new();
+    if !dir.is_dir() {
+        return Ok(result);
+    }
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+        if is_executable(&path) {
+            if let Some(name) = path.file_stem().and_then(|n| n.to_str()) {
+                result.insert(name.to_string());
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// find executable `name` in `dir`.
+pub fn find_executable(dir: &Path, name: &str) -> Option<PathBuf> {
+    let mut candidates = vec![dir.join(name)];
+    if cfg!(windows) {
+        candidates.extend(["exe", "cmd", "bat"].iter().map(|ext| dir.join(format!("{}.{}", name, ext))));
+    }
+    candidates.into_iter().find(|p| is_executable(p))
+}
+
+#[cfg(not(windows))]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| ["exe", "cmd", "bat"].contains(&e.to_lowercase().as_str()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_executable, get_executable_names, write_shims};
+    use crate::tool::test_dir::TestDir;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_write_shims() {
+        let test_dir = TestDir::new("write_shims");
+        let root = test_dir.path();
+        let uvm = root.join("uvm");
+        let names: BTreeSet<_> = ["go".to_string(), "gofmt".to_string()].into();
+        write_shims(root, &uvm, "go", &names).unwrap();
+        write_shims(root, &uvm, "node", &["node".to_string()].into()).unwrap();
+        write_shims(root, &uvm, "go", &["go".to_string()].into()).unwrap();
+
+        let shims = get_executable_names(root).unwrap();
+        assert_eq!(BTreeSet::from(["go".to_string(), "node".to_string()]), shims);
+        assert!(find_executable(root, "node").is_some());
+        assert!(find_executable(root, "gofmt").is_none());
+    }
+}